@@ -15,4 +15,60 @@ play-resets =
 get-multiples =
     Get Close And
 
-    Go For Multiples
\ No newline at end of file
+    Go For Multiples
+
+choose-arena = Choose An Arena
+arena-classic = Classic
+arena-sprint = Sprint
+arena-pillars = Pillars
+power-ups-on = Power-Ups: On
+power-ups-off = Power-Ups: Off
+ball-count = Balls:
+reconnect-controller = Reconnect Controller
+reconnect-other = Or Press A On Another Controller
+
+settings = Settings
+setting-on = On
+setting-off = Off
+master-volume = Master Volume
+music-volume = Music Volume
+effects-volume = Effects Volume
+fullscreen = Fullscreen
+window-scale = Window Scale
+window-scale-fit = Fit
+screen-shake = Screen Shake
+rumble = Rumble
+default-power-ups = Default Power-Ups
+default-ball-count = Default Balls
+language = Language
+stick-deadzone = Stick Deadzone
+calibrate-sticks = Calibrate Sticks
+sticks-default = Default
+sticks-calibrated = Calibrated
+
+offline = Offline
+pause = Pause
+continue = Continue
+restart = Restart
+forfeit = Forfeit
+quit = Quit
+play-again = Play Again
+select-teams = Select Teams
+color-red = Red
+color-blue = Blue
+color-green = Green
+red-team = { color-red } Team
+blue-team = { color-blue } Team
+green-team = { color-green } Team
+red-team-wins = Red Team Wins!!!
+blue-team-wins = Blue Team Wins!!!
+green-team-wins = Green Team Wins!!!
+ready = Ready!
+not-ready = Not Ready
+play-both = Play Both
+press-start = Press Start
+back = Back
+go-back = Hold To Go Back
+touch-shoot = Shoot
+touch-pass = Pass
+touch-start = Start
@@ -0,0 +1,74 @@
+how-to-play = Cómo Jugar
+rules = Reglas
+controls = Controles
+twin-stick-controls = Controles De Doble Stick
+
+get-ball = Consigue El Balón
+kick-it =
+    Chútalo Contra
+    
+    Los Objetivos Rivales
+play-resets = 
+    El Juego Se Reinicia
+    
+    Al Marcar
+get-multiples =
+    Acércate Y
+
+    Busca Varios
+
+choose-arena = Elige Un Estadio
+arena-classic = Clásico
+arena-sprint = Sprint
+arena-pillars = Pilares
+power-ups-on = Potenciadores: Sí
+power-ups-off = Potenciadores: No
+ball-count = Balones:
+reconnect-controller = Reconecta El Mando
+reconnect-other = O Pulsa A En Otro Mando
+
+settings = Ajustes
+setting-on = Sí
+setting-off = No
+master-volume = Volumen General
+music-volume = Volumen De Música
+effects-volume = Volumen De Efectos
+fullscreen = Pantalla Completa
+window-scale = Escala De Ventana
+window-scale-fit = Ajustar
+screen-shake = Temblor De Pantalla
+rumble = Vibración
+default-power-ups = Potenciadores
+default-ball-count = Balones
+language = Idioma
+stick-deadzone = Zona Muerta
+calibrate-sticks = Calibrar Palancas
+sticks-default = Normal
+sticks-calibrated = Calibradas
+
+offline = Local
+pause = Pausa
+continue = Continuar
+restart = Reiniciar
+forfeit = Rendirse
+quit = Salir
+play-again = Otra Vez
+select-teams = Equipos
+color-red = Rojo
+color-blue = Azul
+color-green = Verde
+red-team = Equipo { color-red }
+blue-team = Equipo { color-blue }
+green-team = Equipo { color-green }
+red-team-wins = ¡Gana El Rojo!!!
+blue-team-wins = ¡Gana El Azul!!!
+green-team-wins = ¡Gana El Verde!!!
+ready = ¡Listo!
+not-ready = No Listo
+play-both = Juega Ambos
+press-start = Pulsa Start
+back = Volver
+go-back = Mantén Para Volver
+touch-shoot = Tirar
+touch-pass = Pasar
+touch-start = Inicio
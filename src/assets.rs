@@ -5,14 +5,41 @@ use super::*;
 #[repr(C)]
 pub struct Data {
     pub matchmaking_server: String,
+    /// The language used when [`Settings::language`] is unset or isn't in [`Data::languages`].
     pub localization: Handle<LocalizationAsset>,
+    /// The languages that can be picked in the settings.
+    pub languages: SVec<LanguageMeta>,
     pub screen_size: Vec2,
     pub constant: Constants,
     pub sprite: Sprites,
     pub sound: Sounds,
+    pub rumble: RumbleAssets,
+    pub screen_shake: ScreenShakeMeta,
     pub font: Fonts,
     pub menu: Menus,
-    pub court: SizedImageAsset,
+    pub arenas: SVec<Handle<ArenaAsset>>,
+    pub rules: MatchRules,
+    pub power_up: PowerUpConstants,
+}
+
+impl Data {
+    /// The localization for `language`, falling back to [`Data::localization`].
+    pub fn locale(&self, language: &str) -> Handle<LocalizationAsset> {
+        self.languages
+            .iter()
+            .find(|meta| meta.code == language)
+            .map_or(self.localization, |meta| meta.localization)
+    }
+}
+
+#[derive(HasSchema, Clone, Default)]
+#[repr(C)]
+pub struct LanguageMeta {
+    /// The language's identifier, saved in [`Settings::language`].
+    pub code: String,
+    /// The language's name in its own language.
+    pub name: String,
+    pub localization: Handle<LocalizationAsset>,
 }
 
 #[derive(HasSchema, Clone, Default)]
@@ -24,9 +51,10 @@ pub struct Fonts {
     pub small_inner: Handle<Font>,
 }
 
-#[derive(HasSchema, Clone, Default)]
+#[derive(HasSchema, Clone, Default, PartialEq)]
 #[repr(C)]
 pub struct Sounds {
+    pub mix: AudioMixMeta,
     pub menu_music: VolumeSoundAsset,
     pub countdown_first: VolumeSoundAsset,
     pub countdown_final: VolumeSoundAsset,
@@ -34,13 +62,16 @@ pub struct Sounds {
     pub pin_explosion: VolumeSoundAsset,
     pub ball_spin: VolumeSoundAsset,
     pub ball_spin_buffer: f32,
+    /// The impact speed a ball bounce is heard at full volume, slower bounces are quieter.
+    pub ball_bounce_speed: f32,
     pub ball_bounced: VolumeSoundAsset,
     pub ball_kicked: VolumeSoundAsset,
     pub player_tackle: VolumeSoundAsset,
     pub player_tackled: VolumeSoundAsset,
+    pub power_up: VolumeSoundAsset,
 }
 
-#[derive(HasSchema, Clone, Default)]
+#[derive(HasSchema, Clone, Default, PartialEq)]
 #[repr(C)]
 pub struct Constants {
     pub ball_bounds: Vec2,
@@ -54,6 +85,9 @@ pub struct Constants {
     pub tackle_friction: f32,
     pub kick_power: f32,
     pub player_radius: f32,
+    /// How much of a running teammate's movement to aim ahead of when passing,
+    /// from `0.0` for passing straight at them up to `1.0` for where they'll be.
+    pub pass_lead: f32,
 
     pub kick_frames: u64,
     pub tackle_frames: u64,
@@ -61,6 +95,8 @@ pub struct Constants {
     pub pass_frames: u64,
     pub recieve_frames: u64,
     pub turn_frames: u64,
+    /// The frames shoot, pass & tackle presses are remembered until an action uses them.
+    pub input_buffer_frames: u32,
     pub dribble_smoothing: f32,
     pub dribble_smoothing_threshold: f32,
 
@@ -72,36 +108,115 @@ pub struct Constants {
     pub pin_radius: f32,
 }
 
-#[derive(HasSchema, Clone, Default)]
+impl Constants {
+    /// Where `x` is across the court, for panning sounds.
+    pub fn pan(&self, x: f32) -> f64 {
+        (x / self.ball_bounds.x).clamp(-1.0, 1.0) as f64
+    }
+}
+
+#[derive(HasSchema, Clone, Default, PartialEq)]
 #[repr(C)]
 pub struct Sprites {
     pub ball: Handle<Atlas>,
 
-    pub player_a: Handle<Atlas>,
-    pub player_b: Handle<Atlas>,
-    pub player_a2: Handle<Atlas>,
-    pub player_b2: Handle<Atlas>,
+    /// The colors teams can wear, the first two being team A's & team B's to begin with.
+    /// Other than the first, their sprites are usually a [`RecolorAsset`] of the first's.
+    pub team_colors: SVec<TeamColorMeta>,
+    /// How far apart the [`TeamColorMeta::color`] of each team has to be to tell them apart.
+    pub team_color_distance: f32,
     pub player_animations: Handle<AnimationBankAsset>,
     pub lstick_indicator: Handle<Image>,
     pub rstick_indicator: Handle<Image>,
-    pub p1_shadow: Handle<Image>,
-    pub p2_shadow: Handle<Image>,
-    pub p3_shadow: Handle<Image>,
-    pub p4_shadow: Handle<Image>,
     pub aim_cone: Handle<Image>,
     pub aim_arrow: Handle<Image>,
 
-    pub a_pin: Handle<Atlas>,
-    pub b_pin: Handle<Atlas>,
+    pub round_obstacle: Handle<Image>,
+    pub rect_obstacle: Handle<Image>,
+    /// The width & height of the obstacle images, used to scale them to each obstacle.
+    pub obstacle_image_size: f32,
+
+    pub speed_boost: Handle<Image>,
+    pub mega_kick: Handle<Image>,
+    pub tackle_immunity: Handle<Image>,
+    pub pin_shield: Handle<Image>,
+    pub multi_ball: Handle<Image>,
 }
 impl Sprites {
-    pub fn player_shadows(&self) -> [Handle<Image>; 4] {
-        [
-            self.p1_shadow,
-            self.p2_shadow,
-            self.p3_shadow,
-            self.p4_shadow,
-        ]
+    /// The color at `index` in [`Sprites::team_colors`], wrapping around past the end.
+    pub fn team_color(&self, index: usize) -> &TeamColorMeta {
+        self.team_colors
+            .get(index % self.team_colors.len().max(1))
+            .expect("at least one team color is required in `game.yaml`")
+    }
+    /// Whether the two colors are too alike for the teams to wear them against each other.
+    pub fn team_colors_clash(&self, a: usize, b: usize) -> bool {
+        let (a, b) = (self.team_color(a).color, self.team_color(b).color);
+        a == b || a.distance(b) < self.team_color_distance
+    }
+    pub fn player_atlas(&self, color: usize, outfit: usize) -> Handle<Atlas> {
+        self.team_color(color).outfit(outfit)
+    }
+    pub fn stick_indicator(&self, slot: PlayerSlot) -> Handle<Image> {
+        if slot.is_primary() {
+            self.lstick_indicator
+        } else {
+            self.rstick_indicator
+        }
+    }
+    pub fn obstacle(&self, obstacle: &Obstacle) -> Handle<Image> {
+        if obstacle.is_round() {
+            self.round_obstacle
+        } else {
+            self.rect_obstacle
+        }
+    }
+    pub fn power_up(&self, kind: PowerUpKind) -> Handle<Image> {
+        match kind {
+            PowerUpKind::SpeedBoost => self.speed_boost,
+            PowerUpKind::MegaKick => self.mega_kick,
+            PowerUpKind::TackleImmunity => self.tackle_immunity,
+            PowerUpKind::PinShield => self.pin_shield,
+            PowerUpKind::MultiBall => self.multi_ball,
+        }
+    }
+}
+
+/// A color for a team to wear, with everything drawn in it.
+#[derive(HasSchema, Clone, Default, PartialEq)]
+#[repr(C)]
+pub struct TeamColorMeta {
+    /// The localization key of the color's name.
+    pub name: String,
+    /// The localization key of the team's name shown over its side of team select.
+    pub team: String,
+    /// The localization key of the banner shown when the team wearing it wins.
+    pub wins: String,
+    /// The main color used in the menus, with each channel from 0 to 255.
+    pub color: Vec3,
+    /// A lighter color to go with [`TeamColorMeta::color`], with each channel from 0 to 255.
+    pub highlight: Vec3,
+    /// The character sprites players pick between, teammates start on different ones.
+    pub outfits: SVec<Handle<Atlas>>,
+    /// The shadow drawn under players.
+    pub shadow: Handle<Image>,
+    pub pin: Handle<Atlas>,
+}
+impl TeamColorMeta {
+    /// The outfit at `index`, wrapping around past the end.
+    pub fn outfit(&self, index: usize) -> Handle<Atlas> {
+        self.outfits
+            .get(index % self.outfits.len().max(1))
+            .copied()
+            .expect("every team color needs at least one outfit in `game.yaml`")
+    }
+    pub fn egui_color(&self) -> egui::Color32 {
+        let [r, g, b] = self.color.to_array().map(|channel| channel as u8);
+        egui::Color32::from_rgb(r, g, b)
+    }
+    pub fn egui_highlight(&self) -> egui::Color32 {
+        let [r, g, b] = self.highlight.to_array().map(|channel| channel as u8);
+        egui::Color32::from_rgb(r, g, b)
     }
 }
 
@@ -117,8 +232,11 @@ pub struct Menus {
     pub splash: SplashAssets,
     pub how_to_play: HowToPlayAssets,
     pub team_select: TeamSelectAssets,
-    pub match_done: MatchDoneAssets,
-    pub pause: PauseAssets,
+    pub arena_select: ArenaSelectAssets,
+    pub match_done: TextMenuAssets,
+    pub pause: TextMenuAssets,
+    pub settings: SettingsMenuAssets,
+    pub touch_controls: TouchControlsAssets,
 }
 
 #[derive(HasSchema, Clone, Copy, Default)]
@@ -155,7 +273,7 @@ impl StaticImageAsset {
     }
 }
 
-#[derive(HasSchema, Clone, Copy, Default)]
+#[derive(HasSchema, Clone, Copy, Default, PartialEq)]
 #[repr(C)]
 pub struct SizedImageAsset(pub Handle<Image>, pub u32, pub u32);
 impl SizedImageAsset {
@@ -205,7 +323,7 @@ impl std::ops::Deref for SizedImageAsset {
     }
 }
 
-#[derive(HasSchema, Clone, Copy, Default)]
+#[derive(HasSchema, Clone, Copy, Default, PartialEq)]
 #[repr(C)]
 pub struct VolumeSoundAsset(pub Handle<AudioSource>, pub f64);
 impl VolumeSoundAsset {
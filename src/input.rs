@@ -4,16 +4,147 @@ pub struct LocalInputGamePlugin;
 impl GamePlugin for LocalInputGamePlugin {
     fn install(self, game: &mut Game) {
         game.insert_shared_resource(LocalInputs::default());
+        game.init_shared_resource::<TouchInput>();
         game.systems.add_before_system(LocalInputs::update);
         game.systems.add_after_system(LocalInputs::advance);
     }
 }
 
+/// How a controller's analog sticks are read, to make up for drift & suit the player.
+#[derive(HasSchema, Clone, Copy, Debug, PartialEq)]
+#[repr(C)]
+pub struct InputProfile {
+    /// Sticks closer to their center than this are read as centered.
+    pub radial_deadzone: f32,
+    /// Each axis closer to the center than this is read as zero,
+    /// which makes straight lines easier to hold.
+    pub axial_deadzone: f32,
+    /// The exponent applied to how far the stick is pushed.
+    /// `1.0` is linear, and higher gives finer control near the center.
+    pub response_curve: f32,
+    /// How far a stick has to move to press its equivalent 'button'.
+    pub stroke: f32,
+    /// The resting position of the left stick, found by calibrating.
+    pub left_center: Vec2,
+    /// The resting position of the right stick, found by calibrating.
+    pub right_center: Vec2,
+    /// How strongly the controller rumbles, from `0.0` for not at all up to `1.0`.
+    pub rumble: f32,
+}
+impl Default for InputProfile {
+    fn default() -> Self {
+        Self {
+            radial_deadzone: 0.2,
+            axial_deadzone: 0.0,
+            response_curve: 1.0,
+            stroke: 0.5,
+            left_center: Vec2::ZERO,
+            right_center: Vec2::ZERO,
+            rumble: 1.0,
+        }
+    }
+}
+impl InputProfile {
+    /// Turns a raw stick position into the one used by the game.
+    pub fn apply(&self, raw: Vec2, center: Vec2) -> Vec2 {
+        let mut stick = raw - center;
+        if stick.x.abs() < self.axial_deadzone {
+            stick.x = 0.0;
+        }
+        if stick.y.abs() < self.axial_deadzone {
+            stick.y = 0.0;
+        }
+        let length = stick.length();
+        if length <= self.radial_deadzone {
+            return Vec2::ZERO;
+        }
+        // Rescale so the stick still reaches every value just outside the deadzone.
+        let range = (1.0 - self.radial_deadzone).max(f32::EPSILON);
+        let pushed = ((length - self.radial_deadzone) / range).clamp(0.0, 1.0);
+        stick / length * pushed.powf(self.response_curve.max(f32::EPSILON))
+    }
+}
+
+/// The [`InputProfile`] of every controller that changed theirs, kept between runs in
+/// bones' [`Storage`] by their [`profile_key`].
+#[derive(HasSchema, Clone, Default)]
+pub struct InputProfiles {
+    pub profiles: SMap<Ustr, InputProfile>,
+}
+impl InputProfiles {
+    pub fn load(storage: &Storage) -> Self {
+        storage.get::<InputProfiles>().cloned().unwrap_or_default()
+    }
+    pub fn save(&self, storage: &mut Storage) {
+        storage.insert(self.clone());
+        storage.save();
+    }
+    pub fn get(&self, key: Ustr) -> InputProfile {
+        self.profiles.get(&key).copied().unwrap_or_default()
+    }
+}
+
+/// What a controller's profile is saved under. Gamepad ids are handed out in the order
+/// controllers are found, so this is the controller model's UUID from gilrs instead,
+/// which stays the same between runs. Controllers of the same model share a profile.
+pub fn profile_key(gamepad_id: u32) -> Ustr {
+    if gamepad_id == TOUCH_GAMEPAD {
+        return ustr("touch");
+    }
+    // bones reads the gamepads through this context, the ids it gives are its gilrs ids.
+    let gilrs = bones_framework::input::gilrs::GILRS_CONTEXT.lock().unwrap();
+    let uuid = gilrs
+        .gamepads()
+        .find(|(id, _)| usize::from(*id) as u32 == gamepad_id)
+        .map(|(_, gamepad)| gamepad.uuid());
+    match uuid {
+        Some(uuid) => ustr(
+            &uuid
+                .iter()
+                .map(|byte| format!("{byte:02x}"))
+                .collect::<String>(),
+        ),
+        None => ustr(&format!("gamepad {gamepad_id}")),
+    }
+}
+
+/// The id the on-screen touch controls are given among the gamepads.
+pub const TOUCH_GAMEPAD: u32 = u32::MAX;
+
+/// What the on-screen touch controls are pressing, applied to [`TOUCH_GAMEPAD`]
+/// the way a gamepad's events are applied to its [`LocalInput`].
+#[derive(HasSchema, Clone, Copy, Default, Debug, PartialEq)]
+pub struct TouchInput {
+    /// Whether the screen has been touched, the touch gamepad only exists after.
+    pub active: bool,
+    pub stick: Vec2,
+    pub shoot: bool,
+    pub pass: bool,
+    pub start: bool,
+}
+impl TouchInput {
+    pub fn apply(&self, input: &mut LocalInput) {
+        input.raw_left_stick = self.stick;
+        input.south.apply_bool(self.shoot);
+        input.west.apply_bool(self.pass);
+        input.start.apply_bool(self.start);
+    }
+}
+
 /// The primary layer of individual input.
 #[derive(HasSchema, Clone, Default)]
 pub struct LocalInput {
+    /// The left stick after the [`InputProfile`] is applied.
     pub left_stick: Vec2,
+    /// The right stick after the [`InputProfile`] is applied.
     pub right_stick: Vec2,
+    pub raw_left_stick: Vec2,
+    pub raw_right_stick: Vec2,
+    pub profile: InputProfile,
+    /// The [`profile_key`] the profile is saved under.
+    pub profile_key: Ustr,
+    /// Whether the controller was unplugged and hasn't come back yet.
+    pub disconnected: bool,
     pub up: PressInput,
     pub down: PressInput,
     pub left: PressInput,
@@ -30,22 +161,16 @@ pub struct LocalInput {
 }
 impl LocalInput {
     pub fn apply_gamepad_input(&mut self, event: &GamepadEvent) {
-        /// The distance the stick has to move to press its equivalent 'button'.
-        const STROKE: f32 = 0.5;
         match event {
+            GamepadEvent::Connection(GamepadConnectionEvent { event, .. }) => match event {
+                GamepadConnectionEventKind::Connected => self.disconnected = false,
+                GamepadConnectionEventKind::Disconnected => self.disconnect(),
+            },
             GamepadEvent::Axis(GamepadAxisEvent { axis, value, .. }) => match axis {
-                GamepadAxis::LeftStickX => {
-                    self.left_stick.x = *value;
-                    self.right.apply_bool(*value > STROKE);
-                    self.left.apply_bool(*value < -STROKE);
-                }
-                GamepadAxis::LeftStickY => {
-                    self.left_stick.y = *value;
-                    self.up.apply_bool(*value > STROKE);
-                    self.down.apply_bool(*value < -STROKE);
-                }
-                GamepadAxis::RightStickX => self.right_stick.x = *value,
-                GamepadAxis::RightStickY => self.right_stick.y = *value,
+                GamepadAxis::LeftStickX => self.raw_left_stick.x = *value,
+                GamepadAxis::LeftStickY => self.raw_left_stick.y = *value,
+                GamepadAxis::RightStickX => self.raw_right_stick.x = *value,
+                GamepadAxis::RightStickY => self.raw_right_stick.y = *value,
                 GamepadAxis::LeftZ => {}
                 GamepadAxis::RightZ => {}
                 GamepadAxis::Other(_) => {}
@@ -62,9 +187,44 @@ impl LocalInput {
                 GamepadButton::RightTrigger2 => self.right_trigger.apply_value(*value),
                 _ => {}
             },
-            _ => {}
         }
     }
+    /// The buttons that navigate menus, south confirms & west goes back.
+    pub fn menu(&self) -> MenuInput {
+        MenuInput {
+            up: self.up,
+            down: self.down,
+            left: self.left,
+            right: self.right,
+            confirm: self.south,
+            back: self.west,
+        }
+    }
+    /// Releases everything so an unplugged controller doesn't keep its last input.
+    pub fn disconnect(&mut self) {
+        *self = Self {
+            profile: self.profile,
+            profile_key: self.profile_key,
+            disconnected: true,
+            ..default()
+        };
+    }
+    /// Updates the sticks & stick directions from the raw stick positions.
+    pub fn apply_profile(&mut self) {
+        let profile = self.profile;
+        self.left_stick = profile.apply(self.raw_left_stick, profile.left_center);
+        self.right_stick = profile.apply(self.raw_right_stick, profile.right_center);
+
+        self.right.apply_bool(self.left_stick.x > profile.stroke);
+        self.left.apply_bool(self.left_stick.x < -profile.stroke);
+        self.up.apply_bool(self.left_stick.y > profile.stroke);
+        self.down.apply_bool(self.left_stick.y < -profile.stroke);
+    }
+    /// Takes the sticks' current positions as their resting positions.
+    pub fn calibrate(&mut self) {
+        self.profile.left_center = self.raw_left_stick;
+        self.profile.right_center = self.raw_right_stick;
+    }
     pub fn advance(&mut self) {
         self.up.advance();
         self.down.advance();
@@ -85,28 +245,83 @@ impl LocalInput {
 /// The primary layer of collective inputs.
 #[derive(HasSchema, Clone, Default, Deref, DerefMut)]
 pub struct LocalInputs {
+    #[deref]
     pub gamepads: SMap<u32, LocalInput>,
+    /// The saved profiles, given to controllers when they're first seen.
+    pub profiles: InputProfiles,
 }
 impl LocalInputs {
-    pub fn get_input(&mut self, gamepad_id: u32) -> &LocalInput {
+    /// Loads the saved profiles, including for the controllers already seen.
+    pub fn load_profiles(&mut self, storage: &Storage) {
+        self.profiles = InputProfiles::load(storage);
+        for (_id, input) in &mut self.gamepads {
+            input.profile = self.profiles.get(input.profile_key);
+        }
+    }
+    /// Saves the profiles of the controllers that changed theirs.
+    pub fn save_profiles(&mut self, storage: &mut Storage) {
+        let mut changed = false;
+        for (_id, input) in &self.gamepads {
+            if input.profile != self.profiles.get(input.profile_key) {
+                self.profiles
+                    .profiles
+                    .insert(input.profile_key, input.profile);
+                changed = true;
+            }
+        }
+        if changed {
+            self.profiles.save(storage);
+        }
+    }
+    pub fn get_input_mut(&mut self, gamepad_id: u32) -> &mut LocalInput {
         if !self.gamepads.contains_key(&gamepad_id) {
-            self.gamepads.insert(gamepad_id, default());
+            let profile_key = profile_key(gamepad_id);
+            let input = LocalInput {
+                profile: self.profiles.get(profile_key),
+                profile_key,
+                ..default()
+            };
+            self.gamepads.insert(gamepad_id, input);
         }
-        self.gamepads.get(&gamepad_id).unwrap()
+        self.gamepads.get_mut(&gamepad_id).unwrap()
+    }
+    /// The menu buttons of every controller.
+    pub fn menu(&self) -> Vec<(u32, MenuInput)> {
+        self.iter()
+            .map(|(gamepad, input)| (*gamepad, input.menu()))
+            .collect()
+    }
+    pub fn get_input(&mut self, gamepad_id: u32) -> &LocalInput {
+        self.get_input_mut(gamepad_id)
+    }
+    /// The profile the controller uses, or will be given once it's seen.
+    pub fn profile(&self, gamepad_id: u32) -> InputProfile {
+        self.gamepads.get(&gamepad_id).map_or_else(
+            || self.profiles.get(profile_key(gamepad_id)),
+            |input| input.profile,
+        )
+    }
+    /// Controllers that were never seen are assumed to be connected.
+    pub fn is_connected(&self, gamepad_id: u32) -> bool {
+        self.gamepads
+            .get(&gamepad_id)
+            .map_or(true, |input| !input.disconnected)
     }
     pub fn update(game: &mut Game) {
-        let LocalInputs { gamepads } = &mut *game.shared_resource_mut::<LocalInputs>().unwrap();
+        let mut local_inputs = game.shared_resource_mut::<LocalInputs>().unwrap();
         let gamepad_inputs = game.shared_resource::<GamepadInputs>().unwrap();
 
         for event in &gamepad_inputs.gamepad_events {
-            let id = event.gamepad_id();
-            let local_input = if gamepads.contains_key(id) {
-                gamepads.get_mut(id).unwrap()
-            } else {
-                gamepads.insert(*id, default());
-                gamepads.get_mut(id).unwrap()
-            };
-            local_input.apply_gamepad_input(event);
+            local_inputs
+                .get_input_mut(*event.gamepad_id())
+                .apply_gamepad_input(event);
+        }
+        let touch = game.shared_resource::<TouchInput>().unwrap();
+        if touch.active {
+            touch.apply(local_inputs.get_input_mut(TOUCH_GAMEPAD));
+        }
+        for (_id, local_input) in &mut local_inputs.gamepads {
+            local_input.apply_profile();
         }
     }
     pub fn advance(game: &mut Game) {
@@ -115,3 +330,79 @@ impl LocalInputs {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn assert_near(a: Vec2, b: Vec2) {
+        assert!(a.distance(b) < 1e-5, "{a} isn't {b}");
+    }
+
+    #[test]
+    fn stick_on_the_deadzone_edge_is_centered() {
+        let profile = InputProfile::default();
+        assert_eq!(profile.apply(Vec2::new(0.2, 0.0), Vec2::ZERO), Vec2::ZERO);
+        assert!(profile.apply(Vec2::new(0.201, 0.0), Vec2::ZERO).x > 0.0);
+    }
+
+    #[test]
+    fn deadzone_is_measured_from_the_center() {
+        let profile = InputProfile::default();
+        let center = Vec2::new(0.1, -0.1);
+        assert_eq!(profile.apply(center, center), Vec2::ZERO);
+        assert_near(profile.apply(Vec2::new(1.1, -0.1), center), Vec2::X);
+    }
+
+    #[test]
+    fn axial_deadzone_only_zeroes_inside_it() {
+        let profile = InputProfile {
+            radial_deadzone: 0.0,
+            axial_deadzone: 0.1,
+            ..default()
+        };
+        assert_near(
+            profile.apply(Vec2::new(0.09, 0.5), Vec2::ZERO),
+            Vec2::Y * 0.5,
+        );
+        let edge = profile.apply(Vec2::new(0.1, 0.5), Vec2::ZERO);
+        assert!(edge.x > 0.0);
+    }
+
+    #[test]
+    fn full_push_reaches_one_on_any_curve() {
+        for response_curve in [0.0, 0.5, 1.0, 3.0] {
+            let profile = InputProfile {
+                response_curve,
+                ..default()
+            };
+            assert_near(profile.apply(Vec2::new(0.0, -1.0), Vec2::ZERO), -Vec2::Y);
+            // Corners of square gates go past one.
+            let corner = profile.apply(Vec2::ONE, Vec2::ZERO);
+            assert_near(corner, Vec2::ONE.normalize());
+        }
+    }
+
+    #[test]
+    fn curve_is_applied_past_the_deadzone() {
+        let profile = InputProfile {
+            response_curve: 2.0,
+            ..default()
+        };
+        // Halfway between the deadzone & the edge.
+        assert_near(
+            profile.apply(Vec2::new(0.6, 0.0), Vec2::ZERO),
+            Vec2::X * 0.25,
+        );
+    }
+
+    #[test]
+    fn whole_stick_deadzone_doesnt_divide_by_zero() {
+        let profile = InputProfile {
+            radial_deadzone: 1.0,
+            ..default()
+        };
+        assert_eq!(profile.apply(Vec2::X, Vec2::ZERO), Vec2::ZERO);
+        assert_near(profile.apply(Vec2::X * 1.5, Vec2::ZERO), Vec2::X);
+    }
+}
@@ -21,6 +21,18 @@ pub use play::*;
 pub mod input;
 pub use input::*;
 
+pub mod rumble;
+pub use rumble::*;
+
+pub mod mixer;
+pub use mixer::*;
+
+pub mod recolor;
+pub use recolor::*;
+
+pub mod settings;
+pub use settings::*;
+
 pub mod utils;
 pub use utils::*;
 
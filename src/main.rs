@@ -19,8 +19,12 @@ fn main() {
     // By inserting `ClearColor` as a shared resource, every session
     // will by default read its own `ClearColor` as `BLACK`.
     game.insert_shared_resource(ClearColor(Color::BLACK));
+    // Loaded from storage by the menu once the renderer has set it up.
+    game.init_shared_resource::<Settings>();
 
     game.install_plugin(LocalInputGamePlugin);
+    game.install_plugin(RumbleGamePlugin);
+    game.install_plugin(MixerGamePlugin);
     game.sessions.create_with(session::UI, UiSessionPlugin);
 
     BonesBevyRenderer::new(game)
@@ -5,8 +5,10 @@ pub enum MenuState {
     #[default]
     Splash,
     HowToPlay,
+    Settings,
     FadeTransition,
     TeamSelect,
+    ArenaSelect,
     InGame,
 }
 pub struct MenuPlugin;
@@ -15,140 +17,297 @@ impl SessionPlugin for MenuPlugin {
         session.init_resource::<MenuState>();
         session.init_resource::<FadeTransition>();
 
-        session.install_plugin(Splash::Offline);
+        session.install_plugin(Splash {
+            visible: true,
+            ..default()
+        });
         session.install_plugin(HowToPlay::default());
         session.install_plugin(Fade::new(0.7, 0.5, Color::BLACK, egui::Order::Tooltip));
         session.install_plugin(TeamSelect::default());
+        session.install_plugin(ArenaSelect::default());
+        session.install_plugin(SettingsMenu::default());
+        session.init_resource::<MatchRules>();
+        // Storage is only ready once the game is running, so this can't happen before startup.
+        session.add_startup_system(|ui: &World| {
+            let settings = Settings::load(&ui.resource::<Storage>());
+            ui.resource_mut::<LocalInputs>()
+                .load_profiles(&ui.resource::<Storage>());
+            settings.apply(ui);
+            *ui.resource_mut::<MatchRules>() = settings.rules(&ui.asset_server().root::<Data>());
+            *ui.resource_mut::<Settings>() = settings;
+        });
         session.install_plugin(Pause::default());
-        session.add_startup_system(|root: Root<Data>, mut audio: ResMut<AudioCenter>| {
-            audio.play_music_advanced(
-                *root.sound.menu_music,
-                root.sound.menu_music.volume(),
-                true,
-                false,
-                0.0,
-                1.0,
-                true,
-            );
+        session.install_plugin(Reconnect::default());
+        session.install_plugin(TouchControls::default());
+        session.add_startup_system(|root: Root<Data>, mut mixer: ResMut<AudioMixer>| {
+            mixer.mix = root.sound.mix;
+            mixer.play_music(root.sound.menu_music);
         });
         session.add_system_to_stage(First, update_menu);
         session.add_system_to_stage(First, update_pause);
+        // After the pause menu so the press that reassigns a controller isn't also read by it.
+        session.add_system_to_stage(First, update_reconnect);
     }
 }
 
-pub fn update_pause(ui: &World) {
-    if *ui.resource::<MenuState>() == MenuState::FadeTransition
-        || ui
-            .resource_mut::<Sessions>()
-            .get_mut(session::PLAY)
-            .is_none()
-    {
-        return;
-    };
-    let mut pause = ui.resource_mut::<Pause>();
-    let local_inputs = ui.resource::<LocalInputs>();
+/// Stops the match & hides its ui under the pause menu.
+fn pause_play(ui: &World) {
+    let mut sessions = ui.resource_mut::<Sessions>();
+    let session = sessions.get_mut(session::PLAY).unwrap();
+    session.world.resource_mut::<Countdown>().visual.add_hide();
+    session.world.resource_mut::<MatchDone>().visual.add_hide();
+    session
+        .world
+        .resource_mut::<ScoreDisplay>()
+        .visual
+        .add_hide();
+    session
+        .world
+        .resource_mut::<WinnerBanner>()
+        .visual
+        .add_hide();
+    session.active = false;
+}
+fn unpause_play(ui: &World) {
+    let mut sessions = ui.resource_mut::<Sessions>();
+    let session = sessions.get_mut(session::PLAY).unwrap();
+    session
+        .world
+        .resource_mut::<Countdown>()
+        .visual
+        .remove_hide();
+    session
+        .world
+        .resource_mut::<MatchDone>()
+        .visual
+        .remove_hide();
+    session
+        .world
+        .resource_mut::<ScoreDisplay>()
+        .visual
+        .remove_hide();
+    session
+        .world
+        .resource_mut::<WinnerBanner>()
+        .visual
+        .remove_hide();
+    session.active = true;
+}
 
-    let unpause = || {
+/// Pauses the match when a controller in it is unplugged, until it's plugged back in
+/// or another controller takes its place.
+pub fn update_reconnect(ui: &World) {
+    if *ui.resource::<MenuState>() == MenuState::FadeTransition {
+        return;
+    }
+    let players = {
         let mut sessions = ui.resource_mut::<Sessions>();
-        let session = sessions.get_mut(session::PLAY).unwrap();
-        session
-            .world
-            .resource_mut::<Countdown>()
-            .visual
-            .remove_hide();
-        session
-            .world
-            .resource_mut::<MatchDone>()
-            .visual
-            .remove_hide();
-        session
-            .world
-            .resource_mut::<ScoreDisplay>()
-            .visual
-            .remove_hide();
-        session
-            .world
-            .resource_mut::<WinnerBanner>()
-            .visual
-            .remove_hide();
-        session.active = true;
+        let Some(play) = sessions.get_world(session::PLAY) else {
+            ui.resource_mut::<Reconnect>().player = Maybe::Unset;
+            return;
+        };
+        let play_mode = play.resource::<PlayMode>();
+        let PlayMode::Offline(players) = &*play_mode else {
+            return;
+        };
+        players
+            .team_a
+            .players
+            .iter()
+            .chain(players.team_b.players.iter())
+            .copied()
+            .collect::<Vec<_>>()
     };
+    let local_inputs = ui.resource::<LocalInputs>();
+    let missing = ui.resource::<Reconnect>().player;
 
-    for (_gamepad, input) in local_inputs.iter() {
-        if input.down.just_pressed() {
-            pause.cycle()
-        }
-        if input.up.just_pressed() {
-            pause.cycle();
-            pause.cycle();
-        }
-        if input.start.just_pressed() {
-            match *pause {
-                Pause::Hidden => {
-                    let mut sessions = ui.resource_mut::<Sessions>();
-                    let session = sessions.get_mut(session::PLAY).unwrap();
-                    session.world.resource_mut::<Countdown>().visual.add_hide();
-                    session.world.resource_mut::<MatchDone>().visual.add_hide();
-                    session
-                        .world
-                        .resource_mut::<ScoreDisplay>()
-                        .visual
-                        .add_hide();
-                    session
-                        .world
-                        .resource_mut::<WinnerBanner>()
-                        .visual
-                        .add_hide();
-                    session.active = false;
-                    *pause = Pause::Continue;
+    match missing {
+        Maybe::Unset => {
+            if let Some(player) = players
+                .iter()
+                .find(|player| !local_inputs.is_connected(player.gamepad))
+            {
+                tracing::info!("controller of P{} disconnected, pausing", player.number + 1);
+                // The pause menu is shown once the controller is back.
+                if !ui.resource::<Pause>().visible {
+                    pause_play(ui);
                 }
-                Pause::Continue | Pause::Restart | Pause::Quit => {
-                    unpause();
-                    *pause = Pause::Hidden;
+                ui.resource_mut::<Pause>().close();
+                // Settings opened from pause close with it, saved like when they're backed out of.
+                let mut settings_menu = ui.resource_mut::<SettingsMenu>();
+                if settings_menu.visible && settings_menu.from_pause {
+                    settings_menu.visible = false;
+                    drop(local_inputs);
+                    let mut storage = ui.resource_mut::<Storage>();
+                    ui.resource_mut::<LocalInputs>().save_profiles(&mut storage);
+                    ui.resource::<Settings>().save(&mut storage);
                 }
+                ui.resource_mut::<Reconnect>().player = Maybe::Set(*player);
             }
         }
-        if input.south.just_pressed() {
-            match *pause {
-                Pause::Continue => {
-                    unpause();
-                    *pause = Pause::Hidden;
-                }
-                Pause::Restart => {
-                    start_fade(
-                        ui,
-                        FadeTransition {
-                            hide: play_hide,
-                            prep: play_prep,
-                            finish: play_finish,
-                        },
-                    );
-                    *pause = Pause::Hidden;
-                }
-                Pause::Quit => {
-                    start_fade(
-                        ui,
-                        FadeTransition {
-                            hide: play_hide,
-                            prep: splash_prep,
-                            finish: splash_finish,
-                        },
-                    );
-                    *pause = Pause::Hidden;
+        Maybe::Set(player) => {
+            let gamepad = if local_inputs.is_connected(player.gamepad) {
+                Some(player.gamepad)
+            } else {
+                local_inputs
+                    .iter()
+                    .find(|(gamepad, input)| {
+                        input.south.just_pressed()
+                            && !input.disconnected
+                            && !players.iter().any(|player| player.gamepad == **gamepad)
+                    })
+                    .map(|(gamepad, _)| *gamepad)
+            };
+            let Some(gamepad) = gamepad else {
+                return;
+            };
+            tracing::info!("P{} is using controller {gamepad}", player.number + 1);
+
+            if let Some(play) = ui.resource_mut::<Sessions>().get_world(session::PLAY) {
+                if let PlayMode::Offline(players) = &mut *play.resource_mut::<PlayMode>() {
+                    for info in players
+                        .team_a
+                        .players
+                        .iter_mut()
+                        .chain(players.team_b.players.iter_mut())
+                    {
+                        if info.number == player.number {
+                            info.gamepad = gamepad;
+                        }
+                    }
                 }
-                Pause::Hidden => {}
             }
+            if let Some(join) = ui.resource_mut::<TeamSelect>().joins.get_mut(player.number) {
+                join.set_gamepad(gamepad);
+            }
+            ui.resource_mut::<Reconnect>().player = Maybe::Unset;
+            ui.resource_mut::<Pause>().open(gamepad);
         }
     }
 }
 
+pub fn update_pause(ui: &World) {
+    if *ui.resource::<MenuState>() == MenuState::FadeTransition {
+        return;
+    }
+    let (gamepads, team) = {
+        let mut sessions = ui.resource_mut::<Sessions>();
+        let Some(play) = sessions.get_world(session::PLAY) else {
+            return;
+        };
+        let play_mode = play.resource::<PlayMode>();
+        let team = ui
+            .resource::<Pause>()
+            .nav
+            .owner
+            .option()
+            .and_then(|gamepad| play_mode.team(gamepad));
+        (play_mode.gamepads(), team)
+    };
+    // The match stays paused until the missing controller is sorted out.
+    if ui.resource::<Reconnect>().player.is_some() {
+        return;
+    }
+    if ui.resource::<SettingsMenu>().visible {
+        settings_update(ui);
+        return;
+    }
+    let local_inputs = ui.resource::<LocalInputs>();
+
+    if !ui.resource::<Pause>().visible {
+        // Anyone in the match can pause, but only they can use the menu after.
+        if let Some((gamepad, _)) = local_inputs
+            .iter()
+            .find(|(gamepad, input)| input.start.just_pressed() && gamepads.contains(gamepad))
+        {
+            pause_play(ui);
+            ui.resource_mut::<Pause>().open(*gamepad);
+        }
+        return;
+    }
+    let mut pause = ui.resource_mut::<Pause>();
+    let owner = pause
+        .nav
+        .owner
+        .option()
+        .and_then(|gamepad| local_inputs.gamepads.get(&gamepad));
+
+    let mut howtoplay = ui.resource_mut::<HowToPlay>();
+    if *howtoplay != HowToPlay::Hidden {
+        if let Some(input) = owner {
+            if input.west.just_pressed() || input.start.just_pressed() {
+                *howtoplay = HowToPlay::Hidden;
+            } else {
+                how_to_play_pages(&mut howtoplay, input);
+            }
+        }
+        return;
+    }
+    if owner.is_some_and(|input| input.start.just_pressed()) {
+        unpause_play(ui);
+        pause.close();
+        return;
+    }
+    let entry = match pause.nav.update(local_inputs.menu()) {
+        Some(MenuEvent::Confirm(index)) => PauseEntry::ALL[index].0,
+        Some(MenuEvent::Back) => PauseEntry::Continue,
+        Some(MenuEvent::Adjust(..)) | None => return,
+    };
+    match entry {
+        PauseEntry::Continue => unpause_play(ui),
+        PauseEntry::Restart => start_fade(
+            ui,
+            FadeTransition {
+                hide: play_hide,
+                prep: play_prep,
+                finish: play_finish,
+            },
+        ),
+        PauseEntry::Controls => {
+            *howtoplay = HowToPlay::SingleStickControls;
+            return;
+        }
+        PauseEntry::Settings => {
+            let mut menu = ui.resource_mut::<SettingsMenu>();
+            *menu = SettingsMenu {
+                visible: true,
+                from_pause: true,
+                ..default()
+            };
+            menu.nav.owner = pause.nav.owner;
+            return;
+        }
+        PauseEntry::Forfeit => {
+            // Online matches don't know which team a controller is on.
+            let Some(team) = team else {
+                return;
+            };
+            unpause_play(ui);
+            if let Some(play) = ui.resource_mut::<Sessions>().get_world(session::PLAY) {
+                forfeit(play, team);
+            }
+        }
+        PauseEntry::Quit => start_fade(
+            ui,
+            FadeTransition {
+                hide: play_hide,
+                prep: splash_prep,
+                finish: splash_finish,
+            },
+        ),
+    }
+    pause.close();
+}
+
 pub fn update_menu(world: &World) {
     let game_state = *world.resource::<MenuState>();
     match game_state {
         MenuState::FadeTransition => fade_transition(world),
         MenuState::Splash => splash_update(world),
         MenuState::HowToPlay => how_to_play_update(world),
+        MenuState::Settings => settings_update(world),
         MenuState::TeamSelect => team_select_update(world),
+        MenuState::ArenaSelect => arena_select_update(world),
         MenuState::InGame => {}
     }
 }
@@ -189,10 +348,14 @@ pub fn start_fade(world: &World, transition: FadeTransition) {
     *world.resource_mut() = transition;
 }
 pub fn splash_hide(world: &World) {
-    *world.resource_mut() = Splash::Hidden;
+    world.resource_mut::<Splash>().visible = false;
 }
 pub fn splash_prep(world: &World) {
-    *world.resource_mut() = Splash::Offline;
+    *world.resource_mut() = Splash {
+        visible: true,
+        ..default()
+    };
+    resume_menu_music(world);
 }
 pub fn splash_finish(world: &World) {
     *world.resource_mut() = MenuState::Splash;
@@ -206,10 +369,26 @@ pub fn team_select_prep(world: &World) {
         ..Default::default()
     };
     world.resource::<EguiCtx>().clear_animations();
+    resume_menu_music(world);
+}
+/// Shows team select again while keeping the current assignments.
+pub fn team_select_return_prep(world: &World) {
+    world.resource_mut::<TeamSelect>().visible = true;
+    world.resource::<EguiCtx>().clear_animations();
 }
 pub fn team_select_finish(world: &World) {
     *world.resource_mut() = MenuState::TeamSelect;
 }
+pub fn arena_select_hide(world: &World) {
+    world.resource_mut::<ArenaSelect>().visible = false;
+}
+pub fn arena_select_prep(world: &World) {
+    world.resource_mut::<ArenaSelect>().visible = true;
+    world.resource::<EguiCtx>().clear_animations();
+}
+pub fn arena_select_finish(world: &World) {
+    *world.resource_mut() = MenuState::ArenaSelect;
+}
 pub fn how_to_play_hide(world: &World) {
     *world.resource_mut() = HowToPlay::Hidden;
 }
@@ -220,12 +399,28 @@ pub fn how_to_play_prep(world: &World) {
 pub fn how_to_play_finish(world: &World) {
     *world.resource_mut() = MenuState::HowToPlay;
 }
+pub fn settings_hide(world: &World) {
+    world.resource_mut::<SettingsMenu>().visible = false;
+}
+pub fn settings_prep(world: &World) {
+    *world.resource_mut() = SettingsMenu {
+        visible: true,
+        ..default()
+    };
+    world.resource::<EguiCtx>().clear_animations();
+}
+pub fn settings_finish(world: &World) {
+    *world.resource_mut() = MenuState::Settings;
+}
 pub fn play_hide(ui: &World) {
     let mut sessions = ui.resource_mut::<Sessions>();
     sessions.delete_play();
 }
 pub fn play_prep(ui: &World) {
     let mut sessions = ui.resource_mut::<Sessions>();
+    let arena = ui
+        .resource::<ArenaSelect>()
+        .arena(&ui.asset_server().root::<Data>());
     let player_signs = ui
         .resource::<TeamSelect>()
         .get_player_signs()
@@ -236,7 +431,16 @@ pub fn play_prep(ui: &World) {
 
     tracing::info!("fade_out, recreating PLAY session; assignments:{player_signs:?}");
 
-    sessions.create_play(PlayMode::Offline(player_signs));
+    sessions.create_play(
+        PlayMode::Offline(player_signs),
+        arena,
+        ui.resource::<MatchRules>().reseeded(),
+    );
+}
+/// Crossfades back to the menu music when leaving a match, it keeps playing otherwise.
+fn resume_menu_music(ui: &World) {
+    let menu_music = ui.asset_server().root::<Data>().sound.menu_music;
+    ui.resource_mut::<AudioMixer>().play_music(menu_music);
 }
 pub fn play_finish(ui: &World) {
     *ui.resource_mut() = MenuState::InGame;
@@ -250,41 +454,76 @@ pub fn play_finish(ui: &World) {
 }
 
 pub fn splash_update(ui: &World) {
-    let mut splash = ui.resource_mut::<Splash>();
-    let inputs = ui.resource::<LocalInputs>();
+    let event = ui
+        .resource_mut::<Splash>()
+        .nav
+        .update(ui.resource::<LocalInputs>().menu());
+    let Some(MenuEvent::Confirm(index)) = event else {
+        return;
+    };
+    let (prep, finish): (fn(&World), fn(&World)) = match SplashEntry::ALL[index].0 {
+        SplashEntry::Offline => (team_select_prep, team_select_finish),
+        SplashEntry::HowToPlay => (how_to_play_prep, how_to_play_finish),
+        SplashEntry::Settings => (settings_prep, settings_finish),
+    };
+    start_fade(
+        ui,
+        FadeTransition {
+            hide: splash_hide,
+            prep,
+            finish,
+        },
+    );
+}
+/// Changes the settings as they're picked, saving them once the menu is closed.
+///
+/// The stick rows change the profile of the controller that last used the menu.
+pub fn settings_update(ui: &World) {
+    let mut local_inputs = ui.resource_mut::<LocalInputs>();
+    let asset_server = ui.asset_server();
+    let root = asset_server.root::<Data>();
+    let mut menu = ui.resource_mut::<SettingsMenu>();
+    let mut settings = ui.resource_mut::<Settings>();
+    let previous = settings.clone();
 
-    for (_gamepad, input) in inputs.iter() {
-        if input.up.just_pressed() {
-            splash.cycle_up();
+    let start = local_inputs
+        .iter()
+        .any(|(gamepad, input)| input.start.just_pressed() && menu.nav.can_use(*gamepad));
+    let (close, adjust) = match menu.nav.update(local_inputs.menu()) {
+        Some(MenuEvent::Confirm(index)) => (start, Some((index, 1))),
+        Some(MenuEvent::Adjust(index, step)) => (start, Some((index, step))),
+        Some(MenuEvent::Back) => (true, None),
+        None => (start, None),
+    };
+    if let Some((index, step)) = adjust {
+        let row = SettingsRow::ALL[index];
+        if !row.is_input() {
+            row.adjust(&mut settings, step, &root);
+        } else if let Maybe::Set(gamepad) = menu.nav.user {
+            row.adjust_input(local_inputs.get_input_mut(gamepad), step);
         }
-        if input.down.just_pressed() {
-            splash.cycle_down();
+    }
+    if close {
+        let mut storage = ui.resource_mut::<Storage>();
+        local_inputs.save_profiles(&mut storage);
+        settings.save(&mut storage);
+        if menu.from_pause {
+            menu.visible = false;
+        } else {
+            start_fade(
+                ui,
+                FadeTransition {
+                    hide: settings_hide,
+                    prep: splash_prep,
+                    finish: splash_finish,
+                },
+            );
         }
-        if input.south.just_pressed() {
-            match *splash {
-                Splash::Offline => {
-                    start_fade(
-                        ui,
-                        FadeTransition {
-                            hide: splash_hide,
-                            prep: team_select_prep,
-                            finish: team_select_finish,
-                        },
-                    );
-                    return;
-                }
-                Splash::HowToPlay => {
-                    start_fade(
-                        ui,
-                        FadeTransition {
-                            hide: splash_hide,
-                            prep: how_to_play_prep,
-                            finish: how_to_play_finish,
-                        },
-                    );
-                }
-                Splash::Hidden => todo!(),
-            }
+    }
+    if *settings != previous {
+        settings.apply(ui);
+        if settings.rules != previous.rules {
+            *ui.resource_mut::<MatchRules>() = settings.rules(&root);
         }
     }
 }
@@ -304,27 +543,31 @@ pub fn how_to_play_update(ui: &World) {
                 },
             );
         }
-        match *howtoplay {
-            HowToPlay::GameOverview => {
-                if input.right.just_pressed() {
-                    *howtoplay = HowToPlay::SingleStickControls;
-                }
+        how_to_play_pages(&mut howtoplay, input);
+    }
+}
+/// Turns the pages of how to play with left & right.
+fn how_to_play_pages(howtoplay: &mut HowToPlay, input: &LocalInput) {
+    match *howtoplay {
+        HowToPlay::GameOverview => {
+            if input.right.just_pressed() {
+                *howtoplay = HowToPlay::SingleStickControls;
             }
-            HowToPlay::DualStickControls => {
-                if input.left.just_pressed() {
-                    *howtoplay = HowToPlay::SingleStickControls;
-                }
+        }
+        HowToPlay::DualStickControls => {
+            if input.left.just_pressed() {
+                *howtoplay = HowToPlay::SingleStickControls;
             }
-            HowToPlay::SingleStickControls => {
-                if input.left.just_pressed() {
-                    *howtoplay = HowToPlay::GameOverview;
-                }
-                if input.right.just_pressed() {
-                    *howtoplay = HowToPlay::DualStickControls;
-                }
+        }
+        HowToPlay::SingleStickControls => {
+            if input.left.just_pressed() {
+                *howtoplay = HowToPlay::GameOverview;
+            }
+            if input.right.just_pressed() {
+                *howtoplay = HowToPlay::DualStickControls;
             }
-            HowToPlay::Hidden => {}
         }
+        HowToPlay::Hidden => {}
     }
 }
 pub fn team_select_update(ui: &World) {
@@ -332,15 +575,21 @@ pub fn team_select_update(ui: &World) {
     let local_inputs = ui.resource::<LocalInputs>();
     let asset_server = ui.asset_server();
     let root = asset_server.root::<Data>();
+    let nav = ui.resource::<TeamSelect>().nav;
 
     for (gamepad, input) in local_inputs.iter() {
+        if !nav.can_use(*gamepad) {
+            continue;
+        }
         if input.start.just_pressed() && assignments.is_some() {
+            // Whoever starts picks the arena.
+            ui.resource_mut::<ArenaSelect>().open(Maybe::Set(*gamepad));
             start_fade(
                 ui,
                 FadeTransition {
                     hide: team_select_hide,
-                    prep: play_prep,
-                    finish: play_finish,
+                    prep: arena_select_prep,
+                    finish: arena_select_finish,
                 },
             );
             return;
@@ -354,11 +603,7 @@ pub fn team_select_update(ui: &World) {
             || input.right_bump.just_pressed()
         {
             ui.resource_mut::<TeamSelect>().add_gamepad(*gamepad);
-            ui.resource_mut::<GamepadsRumble>().set_rumble(
-                *gamepad,
-                GamepadRumbleIntensity::LIGHT_BOTH,
-                0.2,
-            );
+            ui.resource_mut::<Rumble>().play(*gamepad, root.rumble.join);
         }
         if input.south.just_pressed() {
             ui.resource_mut::<TeamSelect>().ready_gamepad(*gamepad);
@@ -366,6 +611,13 @@ pub fn team_select_update(ui: &World) {
         if input.west.just_pressed() {
             ui.resource_mut::<TeamSelect>().reverse_gamepad(*gamepad);
         }
+        if input.north.just_pressed() {
+            ui.resource_mut::<TeamSelect>().cycle_outfit(*gamepad);
+        }
+        if input.east.just_pressed() {
+            ui.resource_mut::<TeamSelect>()
+                .cycle_gamepad_color(*gamepad, &root.sprite);
+        }
         if input.west.just_held(root.menu.team_select.back_buffer) {
             start_fade(
                 ui,
@@ -376,10 +628,10 @@ pub fn team_select_update(ui: &World) {
                 },
             );
         }
-        if input.left.just_pressed() {
+        if MenuNav::repeated(&input.left) {
             ui.resource_mut::<TeamSelect>().left_gamepad(*gamepad);
         }
-        if input.right.just_pressed() {
+        if MenuNav::repeated(&input.right) {
             ui.resource_mut::<TeamSelect>().right_gamepad(*gamepad);
         }
         if input.right_bump.just_held(20) && input.left_bump.just_held(20) {
@@ -395,3 +647,68 @@ pub fn team_select_update(ui: &World) {
         }
     }
 }
+/// Picks the arena & rules with the controller that started the match.
+///
+/// Up & down change the balls, the picker only has the one item to focus.
+pub fn arena_select_update(ui: &World) {
+    let local_inputs = ui.resource::<LocalInputs>();
+    let arena_count = ui.asset_server().root::<Data>().arenas.len();
+    let mut arena_select = ui.resource_mut::<ArenaSelect>();
+
+    let mut start = false;
+    for (gamepad, input) in local_inputs.iter() {
+        if !arena_select.nav.can_use(*gamepad) {
+            continue;
+        }
+        start |= input.start.just_pressed();
+        if input.north.just_pressed() {
+            let mut rules = ui.resource_mut::<MatchRules>();
+            rules.power_ups = !rules.power_ups;
+        }
+        if MenuNav::repeated(&input.up) {
+            let mut rules = ui.resource_mut::<MatchRules>();
+            rules.ball_count = (rules.ball_count + 1).min(MatchRules::MAX_BALL_COUNT);
+        }
+        if MenuNav::repeated(&input.down) {
+            let mut rules = ui.resource_mut::<MatchRules>();
+            rules.ball_count = rules.ball_count.saturating_sub(1).max(1);
+        }
+    }
+    let back = match arena_select.nav.update(local_inputs.menu()) {
+        Some(MenuEvent::Adjust(_, -1)) => {
+            arena_select.cycle_left(arena_count);
+            false
+        }
+        Some(MenuEvent::Adjust(_, _)) => {
+            arena_select.cycle_right(arena_count);
+            false
+        }
+        Some(MenuEvent::Confirm(_)) => {
+            start = true;
+            false
+        }
+        Some(MenuEvent::Back) => true,
+        None => false,
+    };
+    drop(arena_select);
+
+    if start {
+        start_fade(
+            ui,
+            FadeTransition {
+                hide: arena_select_hide,
+                prep: play_prep,
+                finish: play_finish,
+            },
+        );
+    } else if back {
+        start_fade(
+            ui,
+            FadeTransition {
+                hide: arena_select_hide,
+                prep: team_select_return_prep,
+                finish: team_select_finish,
+            },
+        );
+    }
+}
@@ -0,0 +1,179 @@
+use super::*;
+
+use kira::sound::static_sound::StaticSoundSettings;
+
+pub struct MixerGamePlugin;
+impl GamePlugin for MixerGamePlugin {
+    fn install(self, game: &mut Game) {
+        game.insert_shared_resource(AudioMixer::default());
+        game.systems.add_after_system(AudioMixer::update);
+    }
+}
+
+/// The categories sounds are mixed in.
+#[derive(HasSchema, Clone, Copy, Default, Debug, PartialEq, Eq)]
+pub enum AudioBus {
+    #[default]
+    Sfx,
+    Crowd,
+    Ui,
+}
+
+#[derive(HasSchema, Clone, Copy, Default, Debug, PartialEq)]
+#[repr(C)]
+pub struct AudioMixMeta {
+    pub music: f64,
+    pub sfx: f64,
+    pub crowd: f64,
+    pub ui: f64,
+    /// How loud the music is while ducked, relative to its usual volume.
+    pub duck_volume: f64,
+    /// The seconds music takes to duck and to come back up.
+    pub duck_seconds: f64,
+    /// The seconds the music stays ducked after the last [`AudioMixer::duck`].
+    pub duck_hold: f64,
+    /// The seconds one track takes to fade out and the next to fade in.
+    pub crossfade_seconds: f64,
+    /// How far sounds at the edge of the court are panned,
+    /// from `0.0` for centered to `1.0` for fully to one side.
+    pub pan: f64,
+}
+impl AudioMixMeta {
+    pub fn bus_volume(&self, bus: AudioBus) -> f64 {
+        match bus {
+            AudioBus::Sfx => self.sfx,
+            AudioBus::Crowd => self.crowd,
+            AudioBus::Ui => self.ui,
+        }
+    }
+}
+
+#[derive(HasSchema, Clone, Copy, Default, Debug)]
+pub struct MixedSound {
+    pub bus: AudioBus,
+    pub sound: VolumeSoundAsset,
+    /// Scales the volume of the sound on top of its bus.
+    pub volume: f64,
+    /// Where the sound is heard from, `-1.0` on the left, `0.0` in the center & `1.0` on the right.
+    pub pan: f64,
+}
+
+/// Plays sounds & music through the [`AudioCenter`] with the volume of their bus.
+///
+/// The [`AudioCenter`] only has one music track, so crossfades fade the old track
+/// out before fading the new one in. Fading & ducking are done with its music volume
+/// scale, on top of [`Settings::music_volume`].
+#[derive(HasSchema, Clone, Default)]
+pub struct AudioMixer {
+    pub mix: AudioMixMeta,
+    pub sounds: SVec<MixedSound>,
+    /// The track that should be playing, the current one fades out when this changes.
+    pub music: Maybe<VolumeSoundAsset>,
+    /// The track that's actually playing.
+    pub playing: Maybe<VolumeSoundAsset>,
+    /// How far the playing track has faded in, from `0.0` to `1.0`.
+    pub fade: f64,
+    /// The seconds left until the music comes back up.
+    pub duck_timer: f64,
+    /// How far the music is ducked, from `0.0` for not at all to `1.0` for fully.
+    pub duck: f64,
+    pub last_update: Option<Instant>,
+    /// The volume scales last handed to the [`AudioCenter`].
+    pub scales: Option<[f64; 3]>,
+}
+impl AudioMixer {
+    pub fn play(&mut self, bus: AudioBus, sound: VolumeSoundAsset) {
+        self.play_at(bus, sound, 0.0, 1.0);
+    }
+    /// Plays a sound panned to `pan` with its volume scaled by `volume`.
+    pub fn play_at(&mut self, bus: AudioBus, sound: VolumeSoundAsset, pan: f64, volume: f64) {
+        self.sounds.push(MixedSound {
+            bus,
+            sound,
+            volume,
+            pan,
+        });
+    }
+    /// Crossfades to `music`, it keeps playing if it's already the current track.
+    pub fn play_music(&mut self, music: VolumeSoundAsset) {
+        self.music = Maybe::Set(music);
+    }
+    pub fn stop_music(&mut self) {
+        self.music = Maybe::Unset;
+    }
+    /// Keeps the music down for [`AudioMixMeta::duck_hold`], call it every frame
+    /// for as long as it should stay down.
+    pub fn duck(&mut self) {
+        self.duck_timer = self.mix.duck_hold;
+    }
+    pub fn update(game: &mut Game) {
+        let mut mixer = game.shared_resource_mut::<AudioMixer>().unwrap();
+        let mut audio = game.shared_resource_mut::<AudioCenter>().unwrap();
+        let settings = game.shared_resource::<Settings>().unwrap();
+
+        let now = Instant::now();
+        let delta = mixer
+            .last_update
+            .map_or(0.0, |last| (now - last).as_secs_f64());
+        mixer.last_update = Some(now);
+        let mix = mixer.mix;
+
+        let fade_step = delta / mix.crossfade_seconds.max(f64::EPSILON);
+        if mixer.music == mixer.playing {
+            mixer.fade = (mixer.fade + fade_step).min(1.0);
+        } else {
+            mixer.fade = (mixer.fade - fade_step).max(0.0);
+            if mixer.fade == 0.0 || mixer.playing.is_none() {
+                audio.stop_music(false);
+                if let Maybe::Set(music) = mixer.music {
+                    audio.play_music_advanced(*music, music.volume(), true, false, 0.0, 1.0, false);
+                }
+                mixer.playing = mixer.music;
+                mixer.fade = 0.0;
+            }
+        }
+
+        mixer.duck_timer = (mixer.duck_timer - delta).max(0.0);
+        let duck_step = delta / mix.duck_seconds.max(f64::EPSILON);
+        mixer.duck = if mixer.duck_timer > 0.0 {
+            (mixer.duck + duck_step).min(1.0)
+        } else {
+            (mixer.duck - duck_step).max(0.0)
+        };
+        let duck = 1.0 - mixer.duck * (1.0 - mix.duck_volume);
+
+        let scales = [
+            settings.master_volume,
+            settings.music_volume * mix.music * mixer.fade * duck,
+            settings.effects_volume,
+        ];
+        if mixer.scales != Some(scales) {
+            audio.set_volume_scales(scales[0], scales[1], scales[2]);
+            mixer.scales = Some(scales);
+        }
+        let [main_scale, _, effects_scale] = scales;
+
+        for sound in std::mem::take(&mut mixer.sounds).iter() {
+            let volume = sound.sound.volume() * sound.volume * mix.bus_volume(sound.bus);
+            if sound.pan == 0.0 {
+                audio.play_sound(*sound.sound, volume);
+                continue;
+            }
+            // The audio center can't pan, so these are played on its audio manager
+            // with the same volume scales it applies to its own sounds.
+            let asset_server = game.shared_resource::<AssetServer>().unwrap();
+            let data = asset_server.get(*sound.sound).0.clone().with_settings(
+                StaticSoundSettings::new()
+                    .volume(volume * main_scale * effects_scale)
+                    .panning(0.5 + (sound.pan * mix.pan).clamp(-1.0, 1.0) * 0.5),
+            );
+            if let Err(err) = game
+                .shared_resource_mut::<AudioManager>()
+                .unwrap()
+                .play(data)
+            {
+                tracing::warn!("couldn't play sound: {err}");
+            }
+        }
+    }
+}
@@ -1,7 +1,10 @@
 use super::*;
 
+pub mod arena;
+pub use arena::prelude::*;
 pub mod layers;
 pub mod path2d;
+pub mod reload;
 
 pub mod input;
 pub use input::prelude::*;
@@ -11,6 +14,14 @@ pub mod pin;
 pub use pin::prelude::*;
 pub mod ball;
 pub use ball::prelude::*;
+pub mod obstacle;
+pub use obstacle::prelude::*;
+pub mod powerup;
+pub use powerup::prelude::*;
+pub mod rules;
+pub use rules::prelude::*;
+pub mod shake;
+pub use shake::prelude::*;
 pub mod spawn;
 pub use spawn::prelude::*;
 
@@ -32,6 +43,67 @@ impl Default for PlayMode {
         Self::Offline(default())
     }
 }
+impl PlayMode {
+    /// The local gamepad controlling the character in `slot`.
+    pub fn gamepad(&self, slot: PlayerSlot) -> Option<u32> {
+        match self {
+            PlayMode::Online { .. } => None,
+            PlayMode::Offline(players) => players
+                .team(slot.team())
+                .characters()
+                .into_iter()
+                .find(|player| player.slot == slot)
+                .map(|player| player.gamepad),
+        }
+    }
+    /// The color `team` wears, from [`Sprites::team_colors`].
+    pub fn team_color(&self, team: Team) -> usize {
+        match self {
+            PlayMode::Online { .. } => team as usize,
+            PlayMode::Offline(players) => players.team(team).color,
+        }
+    }
+    /// The outfit of the character in `slot`, teammates alternating when none was picked.
+    pub fn outfit(&self, slot: PlayerSlot) -> usize {
+        match self {
+            PlayMode::Online { .. } => slot.outfit(0),
+            PlayMode::Offline(players) => slot.outfit(
+                players
+                    .team(slot.team())
+                    .characters()
+                    .into_iter()
+                    .find(|player| player.slot == slot)
+                    .map_or(0, |player| player.picked_outfit),
+            ),
+        }
+    }
+    /// The local gamepads taking part in the match.
+    pub fn gamepads(&self) -> Vec<u32> {
+        match self {
+            PlayMode::Online { clientpad, .. } => vec![*clientpad],
+            PlayMode::Offline(players) => players
+                .team_a
+                .players
+                .iter()
+                .chain(players.team_b.players.iter())
+                .map(|player| player.gamepad)
+                .collect(),
+        }
+    }
+    /// The team `gamepad` plays for, the first one if it plays for both.
+    pub fn team(&self, gamepad: u32) -> Option<Team> {
+        match self {
+            PlayMode::Online { .. } => None,
+            PlayMode::Offline(players) => Team::variants().into_iter().find(|team| {
+                players
+                    .team(*team)
+                    .players
+                    .iter()
+                    .any(|player| player.gamepad == gamepad)
+            }),
+        }
+    }
+}
 #[derive(HasSchema, Debug, Clone)]
 pub struct PlayersInfo {
     pub team_a: TeamInfo,
@@ -40,18 +112,31 @@ pub struct PlayersInfo {
 impl Default for PlayersInfo {
     fn default() -> Self {
         Self {
-            team_a: TeamInfo::Single(PlayerInfo {
-                number: 0,
-                gamepad: 0,
-                dual_stick: true,
-                slot: PlayerSlot::A1,
-            }),
-            team_b: TeamInfo::Single(PlayerInfo {
+            team_a: TeamInfo::new([PlayerInfo {
                 number: 0,
                 gamepad: 0,
                 dual_stick: true,
-                slot: PlayerSlot::B1,
-            }),
+                slot: PlayerSlot::new(Team::A, 0),
+                picked_outfit: 0,
+            }]),
+            team_b: TeamInfo {
+                color: 1,
+                ..TeamInfo::new([PlayerInfo {
+                    number: 0,
+                    gamepad: 0,
+                    dual_stick: true,
+                    slot: PlayerSlot::new(Team::B, 0),
+                    picked_outfit: 0,
+                }])
+            },
+        }
+    }
+}
+impl PlayersInfo {
+    pub fn team(&self, team: Team) -> &TeamInfo {
+        match team {
+            Team::A => &self.team_a,
+            Team::B => &self.team_b,
         }
     }
 }
@@ -69,64 +154,81 @@ pub struct PlayerInfo {
     pub dual_stick: bool,
     /// The exact character slot.
     pub slot: PlayerSlot,
+    /// How many outfits past the slot's own the player picked, see [`PlayerSlot::outfit`].
+    pub picked_outfit: usize,
 }
-#[derive(HasSchema, Debug, Clone)]
-pub enum TeamInfo {
-    Single(PlayerInfo),
-    Double(PlayerInfo, PlayerInfo),
-}
-impl Default for TeamInfo {
-    fn default() -> Self {
-        TeamInfo::Double(default(), default())
-    }
+/// The players controlling one team, anywhere from one up to [`PlayerSlot::TEAM_SIZE`] characters.
+#[derive(HasSchema, Debug, Clone, Default)]
+pub struct TeamInfo {
+    /// A dual stick player also controls their slot's partner.
+    pub players: SVec<PlayerInfo>,
+    /// The color the team wears, from [`Sprites::team_colors`].
+    pub color: usize,
 }
 impl TeamInfo {
-    pub fn is_dual_stick(&self) -> bool {
-        matches!(self, Self::Single(..))
-    }
-    pub fn primary(&self) -> PlayerInfo {
-        match self.clone() {
-            TeamInfo::Single(player_sign) | TeamInfo::Double(player_sign, _) => player_sign,
+    pub fn new(players: impl IntoIterator<Item = PlayerInfo>) -> Self {
+        let mut team = Self::default();
+        for player in players {
+            team.players.push(player);
         }
+        team
     }
-    pub fn secondary(&self) -> PlayerInfo {
-        match self.clone() {
-            TeamInfo::Single(player_info) => PlayerInfo {
-                slot: player_info.slot.partner(),
-                ..player_info
-            },
-            TeamInfo::Double(_, player_sign) => player_sign,
+    /// Every character on the court for this team, in slot order.
+    pub fn characters(&self) -> Vec<PlayerInfo> {
+        let mut characters = Vec::new();
+        for player in self.players.iter() {
+            characters.push(*player);
+            if player.dual_stick {
+                // The partner's slot gives it a different outfit from the same pick.
+                characters.push(PlayerInfo {
+                    slot: player.slot.partner(),
+                    ..*player
+                });
+            }
         }
+        characters.sort_by_key(|player| player.slot.n);
+        characters
     }
 }
-#[derive(HasSchema, Clone)]
-#[schema(no_default)]
+/// The player entities of each team, in lineup order.
+#[derive(HasSchema, Clone, Default)]
 pub struct PlayerEntSigns {
-    pub a1: Entity,
-    pub a2: Entity,
-    pub b1: Entity,
-    pub b2: Entity,
+    pub team_a: SVec<Entity>,
+    pub team_b: SVec<Entity>,
 }
 impl PlayerEntSigns {
-    pub fn partner(&self, entity: Entity) -> Entity {
-        if entity == self.a1 {
-            self.a2
-        } else if entity == self.a2 {
-            self.a1
-        } else if entity == self.b1 {
-            self.b2
-        } else if entity == self.b2 {
-            self.b1
-        } else {
-            panic!("controller is not assigned to a player")
+    pub fn team(&self, team: Team) -> &SVec<Entity> {
+        match team {
+            Team::A => &self.team_a,
+            Team::B => &self.team_b,
         }
     }
-    pub fn entities(&self) -> [Entity; 4] {
-        [self.a1, self.a2, self.b1, self.b2]
+    pub fn entities(&self) -> Vec<Entity> {
+        self.team_a
+            .iter()
+            .chain(self.team_b.iter())
+            .copied()
+            .collect()
+    }
+    /// The other players on the same team as `entity`.
+    pub fn teammates(&self, entity: Entity) -> Vec<Entity> {
+        [&self.team_a, &self.team_b]
+            .into_iter()
+            .find(|team| team.iter().any(|e| *e == entity))
+            .map(|team| team.iter().copied().filter(|e| *e != entity).collect())
+            .unwrap_or_default()
+    }
+    /// The place of `entity` in its team's lineup, along with the size of the team.
+    pub fn lineup(&self, entity: Entity) -> Option<(usize, usize)> {
+        [&self.team_a, &self.team_b].into_iter().find_map(|team| {
+            team.iter()
+                .position(|e| *e == entity)
+                .map(|place| (place, team.len()))
+        })
     }
 }
 
-#[derive(HasSchema, Clone, Copy, Default)]
+#[derive(HasSchema, Clone, Copy, Default, Debug)]
 pub enum PlayState {
     #[default]
     Countdown,
@@ -140,13 +242,19 @@ pub enum PlayState {
 #[derive(Default)]
 pub struct PlayPlugin {
     pub mode: PlayMode,
+    pub arena: Handle<ArenaAsset>,
+    pub rules: MatchRules,
 }
 impl SessionPlugin for PlayPlugin {
     fn install(self, session: &mut SessionBuilder) {
         session
             .set_priority(session::PLAY_PRIORITY)
             .install_plugin(DefaultSessionPlugin)
-            .install_plugin(self::ScenePlugin { mode: self.mode })
+            .install_plugin(self::ScenePlugin {
+                mode: self.mode,
+                arena: self.arena,
+                rules: self.rules,
+            })
             .install_plugin(self::BehaviorsPlugin)
             .install_plugin(self::PlayUIPlugin)
             .install_plugin(self::FlowPlugin);
@@ -155,6 +263,8 @@ impl SessionPlugin for PlayPlugin {
 
 pub struct ScenePlugin {
     pub mode: PlayMode,
+    pub arena: Handle<ArenaAsset>,
+    pub rules: MatchRules,
 }
 impl SessionPlugin for ScenePlugin {
     fn install(self, session: &mut SessionBuilder) {
@@ -167,9 +277,24 @@ impl SessionPlugin for ScenePlugin {
             }
         };
         session.insert_resource(self.mode);
+        session.insert_resource(Arena(self.arena));
+        session.insert_resource(self.rules);
         session.init_resource::<PlayInputs>();
+        session.init_resource::<RunnerControl>();
+        session.init_resource::<RunnerStatus>();
+        session.init_resource::<Constants>();
+
+        // Copy the constants so they can be tweaked during the match.
+        session.add_startup_system(
+            |asset_server: Res<AssetServer>,
+             arena: Res<Arena>,
+             mut constants: ResMut<Constants>| {
+                *constants = arena.constants(&asset_server);
+            },
+        );
 
         session.install_plugin(Path2dToggle::hidden());
+        session.install_plugin(reload::plugin);
         session.add_system_to_stage(First, fix_camera_size);
         session.add_system_to_stage(Update, toggle_debug_lines);
 
@@ -182,9 +307,12 @@ impl SessionPlugin for BehaviorsPlugin {
     fn install(self, session: &mut SessionBuilder) {
         session
             .install_plugin(StatePlugin)
+            .install_plugin(obstacle::plugin)
             .install_plugin(player::plugin)
             .install_plugin(ball::plugin)
             .install_plugin(pin::plugin)
+            .install_plugin(powerup::plugin)
+            .install_plugin(shake::plugin)
             .install_plugin(LifetimePlugin)
             .install_plugin(FollowPlugin);
     }
@@ -198,6 +326,7 @@ impl SessionPlugin for PlayUIPlugin {
         session.install_plugin(ScoreDisplay::new(3.65));
         session.install_plugin(WinnerBanner::default());
         session.install_plugin(MatchDone::default());
+        session.install_plugin(DevTools::default());
     }
 }
 
@@ -209,20 +338,12 @@ impl SessionPlugin for FlowPlugin {
             target: 7,
             ..Default::default()
         });
-        session.add_startup_system(|root: Root<Data>, mut audio: ResMut<AudioCenter>| {
-            if let Some(kira::sound::PlaybackState::Playing) = audio.music_state() {
-                return;
-            }
-            audio.play_music_advanced(
-                *root.sound.menu_music,
-                root.sound.menu_music.volume(),
-                true,
-                false,
-                0.0,
-                1.0,
-                true,
-            );
-        });
+        // The menu music keeps going if the arena shares it.
+        session.add_startup_system(
+            |asset_server: Res<AssetServer>, arena: Res<Arena>, mut mixer: ResMut<AudioMixer>| {
+                mixer.play_music(asset_server.get(arena.0).music);
+            },
+        );
         session.add_system_to_stage(First, |world: &World| {
             let state = *world.resource::<PlayState>();
             match state {
@@ -269,6 +390,8 @@ impl Score {
 }
 
 pub fn countdown_update(play: &World) {
+    play.resource_mut::<AudioMixer>().duck();
+
     if play.resource_mut::<Countdown>().timer.finished() {
         play.run_system(set_player_states_free, ());
 
@@ -297,10 +420,15 @@ pub fn wait_for_score_update(play: &World) {
 }
 pub fn score_display_update(
     root: Root<Data>,
+    constants: Res<Constants>,
     fade: Res<Fade>,
-    entities: Res<Entities>,
+    mut entities: ResMut<Entities>,
+    player_ent_signs: Res<PlayerEntSigns>,
     pin_score: Res<PinScore>,
-    mut audio: ResMut<AudioCenter>,
+    play_mode: Res<PlayMode>,
+    mut mixer: ResMut<AudioMixer>,
+    mut rumble: ResMut<Rumble>,
+    extra_balls: Comp<ExtraBall>,
     mut balls: CompMut<Ball>,
     mut transforms: CompMut<Transform>,
     mut players: CompMut<Player>,
@@ -310,6 +438,8 @@ pub fn score_display_update(
     mut play_state: ResMut<PlayState>,
     mut score: ResMut<Score>,
 ) {
+    mixer.duck();
+
     if fade.fade_out.just_finished() {
         tracing::info!("fade out for round restart, reseting positions");
 
@@ -317,33 +447,53 @@ pub fn score_display_update(
         // for a potential win.
         score.update_current(*pin_score);
 
-        for (_player_e, (player, state, transform)) in
+        for (player_e, (player, state, transform)) in
             entities.iter_with((&mut players, &mut state, &mut transforms))
         {
-            *transform = new_player_transform(player.id, &root);
+            let (place, team_size) = player_ent_signs.lineup(player_e).unwrap();
+            *transform = new_player_transform(player.team(), place, team_size, &constants);
 
             if score.winner().is_none() {
                 state.current = player::state::wait();
             }
         }
-        for (_ball_e, (ball, transform)) in entities.iter_with((&mut balls, &mut transforms)) {
+        // Balls added during the round don't carry over to the next one.
+        let extra = entities
+            .iter_with(&extra_balls)
+            .map(|(ball_e, _)| ball_e)
+            .collect::<Vec<_>>();
+        for ball_e in extra {
+            entities.kill(ball_e);
+        }
+
+        let x = match score.scorer().unwrap() {
+            Team::A => root.screen_size.x / 10.,
+            Team::B => root.screen_size.x / -10.,
+        };
+        let count = entities.iter_with(&balls).count();
+        let positions = ball::start_positions(count, x, &constants);
+        for ((_ball_e, (ball, transform)), pos) in entities
+            .iter_with((&mut balls, &mut transforms))
+            .zip(positions)
+        {
             ball.velocity = default();
-            transform.translation.y = 0.0;
-            transform.translation.x = match score.scorer().unwrap() {
-                Team::A => root.screen_size.x / 10.,
-                Team::B => root.screen_size.x / -10.,
-            };
+            ball.owner = Maybe::Unset;
+            transform.translation.x = pos.x;
+            transform.translation.y = pos.y;
         }
     }
     if fade.fade_in.just_finished() {
         tracing::info!("fade in for round restart");
         if let Some(team) = score.winner() {
             tracing::info!("winner found, showing winner");
-            winner.team = team;
-            winner.visual.show();
-            winner.timer = Timer::from_seconds(3., TimerMode::Once);
-            audio.play_sound(*root.sound.winner, root.sound.winner.volume());
-            audio.stop_music(false);
+            crown_winner(
+                team,
+                &mut winner,
+                &mut mixer,
+                &mut rumble,
+                &play_mode,
+                &root,
+            );
             *play_state = PlayState::Podium;
         } else {
             tracing::info!("no winner, starting countdown");
@@ -355,6 +505,61 @@ pub fn score_display_update(
     }
 }
 
+/// Shows the banner for `team`, the match done menu follows once it's over.
+fn crown_winner(
+    team: Team,
+    winner: &mut WinnerBanner,
+    mixer: &mut AudioMixer,
+    rumble: &mut Rumble,
+    play_mode: &PlayMode,
+    root: &Data,
+) {
+    winner.team = team;
+    winner.visual.show();
+    winner.timer = Timer::from_seconds(3., TimerMode::Once);
+    mixer.play(AudioBus::Crowd, root.sound.winner);
+    rumble.play_team(play_mode, team, root.rumble.win);
+    mixer.stop_music();
+}
+
+/// Ends the match with the other team winning, the same way as if they had scored the last pin.
+pub fn forfeit(play: &World, team: Team) {
+    if matches!(
+        *play.resource::<PlayState>(),
+        PlayState::Podium | PlayState::MatchDone
+    ) {
+        return;
+    }
+    tracing::info!("team {team:?} forfeited");
+    let team = team.other();
+    {
+        // The winners get all their pins, so the score reads as a win.
+        let mut score = play.resource_mut::<Score>();
+        let mut pin_score = play.resource_mut::<PinScore>();
+        match team {
+            Team::A => pin_score.a = score.target,
+            Team::B => pin_score.b = score.target,
+        }
+        score.update_current(*pin_score);
+        score.update_previous();
+    }
+    match team {
+        Team::A => play.run_system(set_player_states_scored_a, ()),
+        Team::B => play.run_system(set_player_states_scored_b, ()),
+    }
+    play.resource_mut::<Countdown>().visual.hide();
+    play.resource_mut::<ScoreDisplay>().visual.hide();
+    crown_winner(
+        team,
+        &mut play.resource_mut::<WinnerBanner>(),
+        &mut play.resource_mut::<AudioMixer>(),
+        &mut play.resource_mut::<Rumble>(),
+        &play.resource::<PlayMode>(),
+        &play.asset_server().root::<Data>(),
+    );
+    *play.resource_mut() = PlayState::Podium;
+}
+
 fn podium_update(play: &World) {
     let mut winner = play.resource_mut::<WinnerBanner>();
 
@@ -367,12 +572,14 @@ fn podium_update(play: &World) {
 }
 
 fn match_done_update(play: &World) {
-    let match_done = *play.resource::<MatchDone>();
-    if !match_done.visual.shown() {
+    if !play.resource::<MatchDone>().visual.shown() {
         return;
     };
 
+    let menu_music = play.asset_server().root::<Data>().sound.menu_music;
+
     let to_team_select = || {
+        play.resource_mut::<AudioMixer>().play_music(menu_music);
         let mut sessions = play.resource_mut::<Sessions>();
         let ui = sessions.get_world(session::UI).unwrap();
         start_fade(
@@ -397,6 +604,7 @@ fn match_done_update(play: &World) {
         );
     };
     let to_splash = || {
+        play.resource_mut::<AudioMixer>().play_music(menu_music);
         let mut sessions = play.resource_mut::<Sessions>();
         let ui = sessions.get_world(session::UI).unwrap();
         start_fade(
@@ -409,23 +617,22 @@ fn match_done_update(play: &World) {
         );
     };
 
-    let inputs = play.resource::<LocalInputs>();
+    // Only the controllers that played can pick what's next.
+    let gamepads = play.resource::<PlayMode>().gamepads();
+    let inputs = play
+        .resource::<LocalInputs>()
+        .menu()
+        .into_iter()
+        .filter(|(gamepad, _)| gamepads.contains(gamepad));
+    let event = play.resource_mut::<MatchDone>().nav.update(inputs);
 
-    for (_id, input) in inputs.iter() {
-        if input.south.just_pressed() {
-            match match_done.state {
-                MatchDoneState::TeamSelect => to_team_select(),
-                MatchDoneState::PlayAgain => play_again(),
-                MatchDoneState::Quit => to_splash(),
-            }
-            play.resource_mut::<MatchDone>().visual.hide();
-        }
-        if input.up.just_pressed() {
-            play.resource_mut::<MatchDone>().cycle_up();
-        }
-        if input.down.just_pressed() {
-            play.resource_mut::<MatchDone>().cycle_down();
+    if let Some(MenuEvent::Confirm(index)) = event {
+        match MatchDoneState::ENTRIES[index].0 {
+            MatchDoneState::TeamSelect => to_team_select(),
+            MatchDoneState::PlayAgain => play_again(),
+            MatchDoneState::Quit => to_splash(),
         }
+        play.resource_mut::<MatchDone>().visual.hide();
     }
 }
 
@@ -472,9 +679,14 @@ pub fn toggle_debug_lines(inputs: Res<KeyboardInputs>, mut path2ds: ResMut<Path2
     }
 }
 
-fn fix_camera_size(root: Root<Data>, window: Res<Window>, mut cameras: CompMut<Camera>) {
+fn fix_camera_size(
+    asset_server: Res<AssetServer>,
+    arena: Res<Arena>,
+    window: Res<Window>,
+    mut cameras: CompMut<Camera>,
+) {
+    let size = asset_server.get(arena.0).court.size();
     for camera in cameras.iter_mut() {
-        let size = root.court.size();
         let ratio = size.x / size.y;
         let wratio = window.size.x / window.size.y;
         if wratio > ratio {
@@ -0,0 +1,44 @@
+use super::*;
+
+pub mod prelude {
+    pub use super::{Arena, ArenaAsset};
+}
+
+/// A court that matches can be played on, listed in [`Data::arenas`].
+#[derive(HasSchema, Clone, Default, PartialEq)]
+#[type_data(metadata_asset("arena"))]
+#[repr(C)]
+pub struct ArenaAsset {
+    /// The localization key of the arena's name.
+    pub name: String,
+    pub court: SizedImageAsset,
+    pub music: VolumeSoundAsset,
+
+    pub ball_bounds: Vec2,
+    pub player_bounds: Vec2,
+    pub pin_count: usize,
+    pub pin_padding: Vec2,
+
+    pub obstacles: SVec<ObstacleMeta>,
+}
+impl ArenaAsset {
+    /// Overrides the court layout in `constant` with the arena's.
+    pub fn apply(&self, constant: &mut Constants) {
+        constant.ball_bounds = self.ball_bounds;
+        constant.player_bounds = self.player_bounds;
+        constant.pin_count = self.pin_count;
+        constant.pin_padding = self.pin_padding;
+    }
+}
+
+/// The arena the match is being played on.
+#[derive(HasSchema, Clone, Default)]
+pub struct Arena(pub Handle<ArenaAsset>);
+impl Arena {
+    /// The root [`Data::constant`] with the arena's court layout applied.
+    pub fn constants(&self, asset_server: &AssetServer) -> Constants {
+        let mut constant = asset_server.root::<Data>().constant.clone();
+        asset_server.get(self.0).apply(&mut constant);
+        constant
+    }
+}
@@ -1,7 +1,7 @@
 use super::*;
 
 pub mod prelude {
-    pub use super::Ball;
+    pub use super::{Ball, ExtraBall};
 }
 
 #[derive(HasSchema, Clone)]
@@ -25,6 +25,51 @@ impl Default for Ball {
     }
 }
 
+/// Marker for a ball added during a round, which is removed when the round restarts.
+#[derive(HasSchema, Clone, Copy, Default)]
+pub struct ExtraBall;
+
+/// The ball being dribbled by `player_e`, if any.
+pub fn owned_by(
+    entities: &Entities,
+    balls: &ComponentStore<Ball>,
+    player_e: Entity,
+) -> Option<Entity> {
+    entities
+        .iter_with(balls)
+        .find(|(_ball_e, ball)| ball.owner.option() == Some(player_e))
+        .map(|(ball_e, _ball)| ball_e)
+}
+
+/// The closest ball without an owner that is within `reach` of `pos`.
+pub fn nearest_free(
+    entities: &Entities,
+    balls: &ComponentStore<Ball>,
+    transforms: &ComponentStore<Transform>,
+    pos: Vec2,
+    reach: f32,
+) -> Option<Entity> {
+    entities
+        .iter_with(balls)
+        .filter(|(_ball_e, ball)| ball.owner.is_none())
+        .map(|(ball_e, _ball)| {
+            let ball_pos = transforms.get(ball_e).unwrap().translation.xy();
+            (ball_e, ball_pos.distance(pos))
+        })
+        .filter(|(_ball_e, distance)| *distance <= reach)
+        .min_by(|(_, a), (_, b)| a.total_cmp(b))
+        .map(|(ball_e, _distance)| ball_e)
+}
+
+/// Where each of `count` balls is placed at the start of a round, spread out along the y axis.
+pub fn start_positions(count: usize, x: f32, constant: &Constants) -> Vec<Vec2> {
+    let spacing = constant.ball_radius * 4.;
+    let top = (count.max(1) - 1) as f32 * spacing / 2.;
+    (0..count)
+        .map(|n| Vec2::new(x, top - n as f32 * spacing))
+        .collect()
+}
+
 pub fn sprite() -> AnimatedSprite {
     AnimatedSprite {
         frames: vec![0, 1, 2, 3].into(),
@@ -38,12 +83,21 @@ pub fn plugin(session: &mut SessionBuilder) {
     session.add_system_to_stage(Update, update_ball);
 }
 
+/// Harder bounces are louder, up to [`Sounds::ball_bounce_speed`].
+///
+/// `impact` is how fast the ball was moving into the surface before it bounced.
+pub fn bounce_volume(impact: f32, sounds: &Sounds) -> f64 {
+    (impact / sounds.ball_bounce_speed.max(f32::EPSILON)).min(1.0) as f64
+}
+
 pub fn update_ball(
     entities: Res<Entities>,
     root: Root<Data>,
+    constants: Res<Constants>,
     players: Comp<Player>,
+    obstacles: Comp<Obstacle>,
     mut paths: CompMut<Path2d>,
-    mut audio: ResMut<AudioCenter>,
+    mut mixer: ResMut<AudioMixer>,
     mut balls: CompMut<Ball>,
     mut animated_sprites: CompMut<AnimatedSprite>,
     mut transforms: CompMut<Transform>,
@@ -58,7 +112,7 @@ pub fn update_ball(
         dribble_smoothing,
         dribble_smoothing_threshold,
         ..
-    } = root.constant;
+    } = *constants;
 
     let Sounds {
         ball_spin,
@@ -66,6 +120,8 @@ pub fn update_ball(
         ..
     } = root.sound;
 
+    let placed_obstacles = obstacle::placed(&entities, &obstacles, &transforms);
+
     for (ball_entity, (ball, animation)) in entities.iter_with((&mut balls, &mut animated_sprites))
     {
         if let Maybe::Set(target) = ball.owner {
@@ -112,51 +168,73 @@ pub fn update_ball(
             .tick(std::time::Duration::from_secs_f32(ball.velocity.length()));
 
         if ball.sound_timer.just_finished() {
-            audio.play_sound(*ball_spin, ball_spin.volume());
+            let x = transforms.get(ball_entity).unwrap().translation.x;
+            mixer.play_at(AudioBus::Sfx, ball_spin, constants.pan(x), 1.0);
         }
 
         let pos = &mut transforms.get_mut(ball_entity).unwrap().translation;
 
+        // Every surface hit this frame, by the speed into the hardest one before bouncing.
+        let mut impact = None::<f32>;
+        let mut hit = |speed: f32| impact = Some(impact.unwrap_or(0.0).max(speed));
+
+        // Obstacles
+        for (obstacle, obstacle_transform) in &placed_obstacles {
+            let Some((normal, depth)) =
+                obstacle.penetration(obstacle_transform, pos.xy(), ball_radius)
+            else {
+                continue;
+            };
+            pos.x += normal.x * depth;
+            pos.y += normal.y * depth;
+
+            // Bounce off relative to the moving surface so spinning bars can knock the ball.
+            let surface = obstacle.surface_velocity(obstacle_transform, pos.xy());
+            let relative = ball.velocity - surface;
+            if relative.dot(normal) < 0.0 {
+                hit(-relative.dot(normal));
+                let reflected = relative - normal * relative.dot(normal) * 2.;
+                ball.velocity = surface + reflected * ball_etransfer;
+            }
+        }
+
         // Bounds
         let bounds = ball_bounds;
 
         if (bounds.y - pos.y - ball_radius) < 0.0 {
             pos.y = bounds.y - ball_radius;
+            hit(ball.velocity.y.abs());
             ball.velocity.y = -ball.velocity.y;
             ball.velocity.y *= ball_etransfer;
-            if ball.owner.is_none() {
-                ball.bounced = true;
-                audio.play_sound(*ball_bounced, ball_bounced.volume());
-            }
         }
         if (-bounds.y - pos.y + ball_radius) > 0.0 {
             pos.y = -bounds.y + ball_radius;
+            hit(ball.velocity.y.abs());
             ball.velocity.y = -ball.velocity.y;
             ball.velocity.y *= ball_etransfer;
-            ball.bounced = ball.owner.is_none();
-            if ball.owner.is_none() {
-                ball.bounced = true;
-                audio.play_sound(*ball_bounced, ball_bounced.volume());
-            }
         }
         if (bounds.x - pos.x - ball_radius) < 0.0 {
             pos.x = bounds.x - ball_radius;
+            hit(ball.velocity.x.abs());
             ball.velocity.x = -ball.velocity.x;
             ball.velocity.x *= ball_etransfer;
-            ball.bounced = ball.owner.is_none();
-            if ball.owner.is_none() {
-                ball.bounced = true;
-                audio.play_sound(*ball_bounced, ball_bounced.volume());
-            }
         }
         if (-bounds.x - pos.x + ball_radius) > 0.0 {
             pos.x = -bounds.x + ball_radius;
+            hit(ball.velocity.x.abs());
             ball.velocity.x = -ball.velocity.x;
             ball.velocity.x *= ball_etransfer;
-            ball.bounced = ball.owner.is_none();
+        }
+
+        if let Some(impact) = impact {
             if ball.owner.is_none() {
                 ball.bounced = true;
-                audio.play_sound(*ball_bounced, ball_bounced.volume());
+                mixer.play_at(
+                    AudioBus::Sfx,
+                    ball_bounced,
+                    constants.pan(pos.x),
+                    bounce_volume(impact, &root.sound),
+                );
             }
         }
 
@@ -175,3 +253,39 @@ pub fn update_ball(
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn constants() -> Constants {
+        Constants {
+            ball_radius: 2.,
+            ..default()
+        }
+    }
+
+    #[test]
+    fn no_balls_have_no_positions() {
+        assert!(start_positions(0, 0., &constants()).is_empty());
+    }
+
+    #[test]
+    fn one_ball_starts_in_the_middle() {
+        assert_eq!(
+            start_positions(1, 5., &constants()),
+            vec![Vec2::new(5., 0.)]
+        );
+    }
+
+    #[test]
+    fn balls_are_spread_evenly_around_the_middle() {
+        let positions = start_positions(3, 0., &constants());
+        assert_eq!(
+            positions,
+            vec![Vec2::new(0., 8.), Vec2::ZERO, Vec2::new(0., -8.)]
+        );
+        let positions = start_positions(2, 0., &constants());
+        assert_eq!(positions[0].y, -positions[1].y);
+    }
+}
@@ -49,40 +49,22 @@ impl PlayInput {
 /// The second layer of compiled input collection.
 #[derive(HasSchema, Clone, Default, Deref, DerefMut)]
 pub struct PlayInputs {
-    pub clients: [PlayInput; 4usize],
+    /// Indexed by [`PlayerSlot::index`].
+    pub clients: [PlayInput; PlayerSlot::COUNT],
 }
 impl PlayInputs {
     pub fn from_world(world: &World) -> Self {
-        let mut clients = [default(); 4];
+        let mut clients = [default(); PlayerSlot::COUNT];
         let mut local_inputs = world.resource_mut::<LocalInputs>();
 
         match &*world.resource::<crate::play::PlayMode>() {
             crate::PlayMode::Online { .. } => todo!(),
             crate::PlayMode::Offline(PlayersInfo { team_a, team_b }) => {
-                match team_a {
-                    TeamInfo::Single(player_sign) => {
-                        clients[0] =
-                            PlayInput::from_local(local_inputs.get_input(player_sign.gamepad));
-                        clients[1] =
-                            PlayInput::from_local_dual(local_inputs.get_input(player_sign.gamepad));
-                    }
-                    TeamInfo::Double(primary, secondary) => {
-                        clients[0] = PlayInput::from_local(local_inputs.get_input(primary.gamepad));
-                        clients[1] =
-                            PlayInput::from_local(local_inputs.get_input(secondary.gamepad));
-                    }
-                }
-                match team_b {
-                    TeamInfo::Single(player_sign) => {
-                        clients[2] =
-                            PlayInput::from_local(local_inputs.get_input(player_sign.gamepad));
-                        clients[3] =
-                            PlayInput::from_local_dual(local_inputs.get_input(player_sign.gamepad));
-                    }
-                    TeamInfo::Double(primary, secondary) => {
-                        clients[2] = PlayInput::from_local(local_inputs.get_input(primary.gamepad));
-                        clients[3] =
-                            PlayInput::from_local(local_inputs.get_input(secondary.gamepad));
+                for player in team_a.players.iter().chain(team_b.players.iter()) {
+                    let local = local_inputs.get_input(player.gamepad);
+                    clients[player.slot.index()] = PlayInput::from_local(local);
+                    if player.dual_stick {
+                        clients[player.slot.partner().index()] = PlayInput::from_local_dual(local);
                     }
                 }
             }
@@ -92,6 +74,40 @@ impl PlayInputs {
     pub fn get_control(&self, index: usize) -> &PlayInput {
         &self.clients[index]
     }
+    pub fn get_control_mut(&mut self, index: usize) -> &mut PlayInput {
+        &mut self.clients[index]
+    }
+    /// Remembers the presses from a rendered frame's `inputs` until a fixed step takes them.
+    pub fn latch(&mut self, inputs: &PlayInputs) {
+        for (client, input) in self.clients.iter_mut().zip(inputs.clients.iter()) {
+            client.shoot.latch(&input.shoot);
+            client.pass.latch(&input.pass);
+            client.start.latch(&input.start);
+        }
+    }
+    /// Tracks presses from the `previous` fixed step rather than the previous rendered frame,
+    /// adding in the presses `latched` since then. See [`PressInput::step_from`].
+    pub fn step_from(&mut self, previous: &PlayInputs, latched: &PlayInputs) {
+        let clients = self.clients.iter_mut().zip(previous.clients.iter());
+        for ((client, previous), latched) in clients.zip(latched.clients.iter()) {
+            client
+                .shoot
+                .step_from(&previous.shoot, latched.shoot.pressed());
+            client
+                .pass
+                .step_from(&previous.pass, latched.pass.pressed());
+            client
+                .start
+                .step_from(&previous.start, latched.start.pressed());
+        }
+    }
+    /// Keeps action presses from the `previous` step buffered for `frames` steps.
+    pub fn buffer_from(&mut self, previous: &PlayInputs, frames: u32) {
+        for (client, previous) in self.clients.iter_mut().zip(previous.clients.iter()) {
+            client.shoot.buffer_from(&previous.shoot, frames);
+            client.pass.buffer_from(&previous.pass, frames);
+        }
+    }
 }
 
 // #[derive(HasSchema, Clone, Default)]
@@ -1,5 +1,7 @@
 crate::layers![
     COURT,
+    OBSTACLE,
+    POWER_UP,
     AIMCONE,
     AIMARROW,
     HITO_SHADOW,
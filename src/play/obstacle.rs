@@ -0,0 +1,255 @@
+use super::*;
+
+pub mod prelude {
+    pub use super::{Obstacle, ObstacleMeta, ObstacleSprite};
+}
+
+/// An obstacle placed on the court by an [`ArenaAsset`].
+#[derive(HasSchema, Clone, Copy, Default, PartialEq)]
+#[repr(C)]
+pub struct ObstacleMeta {
+    pub pos: Vec2,
+    /// The starting rotation in degrees.
+    pub rotation: f32,
+    /// Makes the obstacle round with this radius, otherwise it is a rectangle of [`Self::size`].
+    pub radius: f32,
+    /// The full width & height of a rectangle obstacle.
+    pub size: Vec2,
+    /// Degrees to rotate every frame, for rotating bars.
+    pub spin: f32,
+}
+
+/// A solid shape on the court that players & balls collide with.
+#[derive(HasSchema, Clone, Copy, Default)]
+pub struct Obstacle {
+    /// Makes the obstacle round with this radius, otherwise it is a rectangle of [`Self::size`].
+    pub radius: f32,
+    /// The full width & height of a rectangle obstacle.
+    pub size: Vec2,
+    /// Degrees to rotate every frame.
+    pub spin: f32,
+}
+/// The image of the [`Obstacle`] it follows, kept apart so the obstacle isn't scaled to fit it.
+#[derive(HasSchema, Clone, Default)]
+pub struct ObstacleSprite;
+
+impl From<ObstacleMeta> for Obstacle {
+    fn from(meta: ObstacleMeta) -> Self {
+        Self {
+            radius: meta.radius,
+            size: meta.size,
+            spin: meta.spin,
+        }
+    }
+}
+impl Obstacle {
+    pub fn is_round(&self) -> bool {
+        self.radius > 0.0
+    }
+    /// The size of the image the obstacle is drawn with.
+    pub fn extents(&self) -> Vec2 {
+        if self.is_round() {
+            Vec2::splat(self.radius * 2.)
+        } else {
+            self.size
+        }
+    }
+    /// Returns the direction to push a circle at `pos` out of the obstacle
+    /// and how far it needs to be pushed, if they overlap.
+    pub fn penetration(
+        &self,
+        transform: &Transform,
+        pos: Vec2,
+        radius: f32,
+    ) -> Option<(Vec2, f32)> {
+        let center = transform.translation.xy();
+
+        if self.is_round() {
+            let diff = pos - center;
+            let distance = diff.length();
+            let touch = self.radius + radius;
+            if distance >= touch {
+                return None;
+            }
+            let normal = if distance > 0.0 {
+                diff / distance
+            } else {
+                Vec2::X
+            };
+            return Some((normal, touch - distance));
+        }
+
+        // The obstacle's rotation as a unit vector, to be used with `Vec2::rotate`.
+        let rotation = (transform.rotation * Vec3::X).xy().normalize_or_zero();
+        let local = Vec2::new(rotation.x, -rotation.y).rotate(pos - center);
+        let half = self.size / 2.;
+        let closest = local.clamp(-half, half);
+
+        let (normal, depth) = if closest == local {
+            // The center is inside, push out through the closest side.
+            let x_depth = half.x - local.x.abs();
+            let y_depth = half.y - local.y.abs();
+            if x_depth < y_depth {
+                (Vec2::X * local.x.signum(), x_depth + radius)
+            } else {
+                (Vec2::Y * local.y.signum(), y_depth + radius)
+            }
+        } else {
+            let diff = local - closest;
+            let distance = diff.length();
+            if distance >= radius {
+                return None;
+            }
+            (diff / distance, radius - distance)
+        };
+        Some((rotation.rotate(normal), depth))
+    }
+    /// The velocity of the obstacle's surface at `pos` caused by its spin.
+    pub fn surface_velocity(&self, transform: &Transform, pos: Vec2) -> Vec2 {
+        let arm = pos - transform.translation.xy();
+        arm.perp() * self.spin.to_radians()
+    }
+}
+
+pub fn plugin(session: &mut SessionBuilder) {
+    session.add_system_to_stage(PreUpdate, spin_obstacles);
+    session.add_system_to_stage(Last, turn_obstacle_sprites);
+}
+
+fn spin_obstacles(
+    entities: Res<Entities>,
+    state: Res<PlayState>,
+    obstacles: Comp<Obstacle>,
+    mut transforms: CompMut<Transform>,
+) {
+    if !matches!(*state, PlayState::WaitForScore) {
+        return;
+    }
+    for (_e, (obstacle, transform)) in entities.iter_with((&obstacles, &mut transforms)) {
+        if obstacle.spin != 0.0 {
+            transform.rotation *= Quat::from_rotation_z(obstacle.spin.to_radians());
+        }
+    }
+}
+
+/// [`Follow`] only moves the sprites, so they're turned to match their obstacle here.
+fn turn_obstacle_sprites(
+    entities: Res<Entities>,
+    obstacle_sprites: Comp<ObstacleSprite>,
+    follows: Comp<Follow>,
+    mut transforms: CompMut<Transform>,
+) {
+    for (sprite_e, (_sprite, follow)) in entities.iter_with((&obstacle_sprites, &follows)) {
+        let Some(rotation) = transforms.get(follow.target()).map(|t| t.rotation) else {
+            continue;
+        };
+        if let Some(transform) = transforms.get_mut(sprite_e) {
+            transform.rotation = rotation;
+        }
+    }
+}
+
+/// The obstacles as they are placed on the court right now.
+pub fn placed(
+    entities: &Entities,
+    obstacles: &ComponentStore<Obstacle>,
+    transforms: &ComponentStore<Transform>,
+) -> Vec<(Obstacle, Transform)> {
+    entities
+        .iter_with((obstacles, transforms))
+        .map(|(_e, (obstacle, transform))| (*obstacle, *transform))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn at(x: f32, y: f32, degrees: f32) -> Transform {
+        Transform {
+            translation: Vec3::new(x, y, 0.),
+            rotation: Quat::from_rotation_z(degrees.to_radians()),
+            ..default()
+        }
+    }
+    fn round(radius: f32) -> Obstacle {
+        Obstacle {
+            radius,
+            ..default()
+        }
+    }
+    fn rect(w: f32, h: f32) -> Obstacle {
+        Obstacle {
+            size: Vec2::new(w, h),
+            ..default()
+        }
+    }
+    fn assert_near(a: Vec2, b: Vec2) {
+        assert!(a.distance(b) < 1e-4, "{a} != {b}");
+    }
+
+    #[test]
+    fn round_only_touching_is_apart() {
+        let obstacle = round(10.);
+        assert_eq!(
+            obstacle.penetration(&at(0., 0., 0.), Vec2::new(13., 0.), 3.),
+            None
+        );
+        let (normal, depth) = obstacle
+            .penetration(&at(0., 0., 0.), Vec2::new(12., 0.), 3.)
+            .unwrap();
+        assert_near(normal, Vec2::X);
+        assert!((depth - 1.).abs() < 1e-4);
+    }
+
+    #[test]
+    fn round_centered_still_pushes_out() {
+        let (normal, depth) = round(10.)
+            .penetration(&at(5., 5., 0.), Vec2::new(5., 5.), 3.)
+            .unwrap();
+        assert_near(normal, Vec2::X);
+        assert_eq!(depth, 13.);
+    }
+
+    #[test]
+    fn rect_point_exactly_on_edge() {
+        let (normal, depth) = rect(20., 10.)
+            .penetration(&at(0., 0., 0.), Vec2::new(10., 0.), 3.)
+            .unwrap();
+        assert_near(normal, Vec2::X);
+        assert_eq!(depth, 3.);
+
+        let (normal, depth) = rect(20., 10.)
+            .penetration(&at(0., 0., 0.), Vec2::new(0., -5.), 3.)
+            .unwrap();
+        assert_near(normal, Vec2::NEG_Y);
+        assert_eq!(depth, 3.);
+    }
+
+    #[test]
+    fn rect_only_touching_is_apart() {
+        assert_eq!(
+            rect(20., 10.).penetration(&at(0., 0., 0.), Vec2::new(13., 0.), 3.),
+            None
+        );
+    }
+
+    #[test]
+    fn rect_corner_pushes_diagonally() {
+        let (normal, depth) = rect(20., 10.)
+            .penetration(&at(0., 0., 0.), Vec2::new(11., 6.), 3.)
+            .unwrap();
+        assert_near(normal, Vec2::ONE.normalize());
+        assert!((depth - (3. - 2f32.sqrt())).abs() < 1e-4);
+    }
+
+    #[test]
+    fn rotated_rect_pushes_out_along_its_turned_side() {
+        // Turned a quarter, the long side runs along y.
+        let (normal, depth) = rect(20., 10.)
+            .penetration(&at(0., 0., 90.), Vec2::new(6., 0.), 3.)
+            .unwrap();
+        assert_near(normal, Vec2::X);
+        assert!((depth - 2.).abs() < 1e-4);
+    }
+}
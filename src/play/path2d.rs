@@ -6,6 +6,7 @@ pub mod color {
     pub const BALL: Color = Color::CYAN;
     pub const PIN: Color = Color::RED;
     pub const BOUNDS: Color = Color::RED;
+    pub const POWER_UP: Color = Color::YELLOW;
 
     pub const FREE: Color = Color::WHITE;
     pub const KICK_GRAB: Color = Color::ORANGE;
@@ -14,12 +15,12 @@ pub mod color {
     pub const DRIBBLE: Color = Color::GREEN;
 }
 
-pub fn bounds(root: &Data) -> Path2d {
+pub fn bounds(constant: &Constants) -> Path2d {
     Path2d {
         color: color::BOUNDS,
         points: [
-            rect_points(root.constant.player_bounds),
-            rect_points(root.constant.ball_bounds),
+            rect_points(constant.player_bounds),
+            rect_points(constant.ball_bounds),
         ]
         .concat(),
         thickness: 1.,
@@ -27,38 +28,58 @@ pub fn bounds(root: &Data) -> Path2d {
     }
 }
 
-pub fn player(root: &Data) -> Path2d {
+pub fn player(constant: &Constants) -> Path2d {
     Path2d {
         color: color::FREE,
         points: [
-            circle_points(root.constant.player_radius, 8),
-            vec![Vec2::ZERO, Vec2::X * root.constant.player_radius],
+            circle_points(constant.player_radius, 8),
+            vec![Vec2::ZERO, Vec2::X * constant.player_radius],
         ]
         .concat(),
         thickness: 1.,
         line_breaks: vec![9],
     }
 }
-pub fn aim_arrow(root: &Data) -> Path2d {
+pub fn aim_arrow(constant: &Constants) -> Path2d {
     Path2d {
         color: color::AIM,
-        points: vec![-Vec2::X * root.constant.player_radius, -Vec2::X * 4.],
+        points: vec![-Vec2::X * constant.player_radius, -Vec2::X * 4.],
         thickness: 1.,
         line_breaks: vec![],
     }
 }
-pub fn ball(root: &Data) -> Path2d {
+pub fn ball(constant: &Constants) -> Path2d {
     Path2d {
         color: color::BALL,
-        points: circle_points(root.constant.ball_radius, 8),
+        points: circle_points(constant.ball_radius, 8),
         thickness: 1.,
         line_breaks: vec![],
     }
 }
-pub fn pin(root: &Data) -> Path2d {
+pub fn obstacle(obstacle: &Obstacle) -> Path2d {
+    Path2d {
+        color: color::BOUNDS,
+        points: if obstacle.is_round() {
+            circle_points(obstacle.radius, 12)
+        } else {
+            rect_points(obstacle.size / 2.)
+        },
+        thickness: 1.,
+        line_breaks: vec![],
+    }
+}
+pub fn power_up(radius: f32) -> Path2d {
+    Path2d {
+        color: color::POWER_UP,
+        points: circle_points(radius, 8),
+        thickness: 1.,
+        line_breaks: vec![],
+    }
+}
+pub fn pin(constant: &Constants) -> Path2d {
     Path2d {
         color: color::PIN,
-        points: circle_points(root.constant.pin_radius, 10),
+        points: circle_points(constant.pin_radius, 10),
         thickness: 1.,
         line_breaks: vec![],
     }
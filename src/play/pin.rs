@@ -59,12 +59,17 @@ pub fn animation_bank() -> AnimationBankSprite {
 pub fn update(
     pins: Comp<Pin>,
     teams: Comp<Team>,
-    balls: Comp<Ball>,
-    transforms: Comp<Transform>,
     entities: Res<Entities>,
     atlases: Comp<AtlasSprite>,
     root: Root<Data>,
-    mut audio: ResMut<AudioCenter>,
+    constants: Res<Constants>,
+    shield: Res<PinShield>,
+    play_mode: Res<PlayMode>,
+    mut balls: CompMut<Ball>,
+    mut transforms: CompMut<Transform>,
+    mut mixer: ResMut<AudioMixer>,
+    mut rumble: ResMut<Rumble>,
+    mut shake: ResMut<ScreenShake>,
     mut score: ResMut<PinScore>,
     mut banks: CompMut<AnimationBankSprite>,
     mut commands: Commands,
@@ -72,25 +77,52 @@ pub fn update(
     let Constants {
         ball_radius,
         pin_radius,
+        ball_etransfer,
         ..
-    } = root.constant;
+    } = *constants;
 
-    let Sounds { pin_explosion, .. } = root.sound;
+    let Sounds {
+        pin_explosion,
+        ball_bounced,
+        ..
+    } = root.sound;
 
     for (pin_e, (_pin, team)) in entities.iter_with((&pins, &teams)) {
         let bank = banks.get_mut(pin_e).unwrap();
         if bank.current == ustr("still") {
             let pin_pos = transforms.get(pin_e).unwrap().translation.xy();
-            for (ball_e, ball) in entities.iter_with(&balls) {
+            for (ball_e, ball) in entities.iter_with(&mut balls) {
                 if ball.owner.is_none() {
                     let ball_pos = transforms.get(ball_e).unwrap().translation.xy();
                     if ball_pos.distance(pin_pos) <= ball_radius + pin_radius {
+                        if shield.is_shielded(*team) {
+                            // Shielded pins are solid, bounce the ball off instead.
+                            let normal = (ball_pos - pin_pos).normalize_or_zero();
+                            let pos = pin_pos + normal * (ball_radius + pin_radius);
+                            let translation = &mut transforms.get_mut(ball_e).unwrap().translation;
+                            translation.x = pos.x;
+                            translation.y = pos.y;
+                            if ball.velocity.dot(normal) < 0.0 {
+                                let impact = -ball.velocity.dot(normal);
+                                ball.velocity -= normal * ball.velocity.dot(normal) * 2.;
+                                ball.velocity *= ball_etransfer;
+                                mixer.play_at(
+                                    AudioBus::Sfx,
+                                    ball_bounced,
+                                    constants.pan(pos.x),
+                                    ball::bounce_volume(impact, &root.sound),
+                                );
+                            }
+                            continue;
+                        }
                         match team {
                             Team::A => score.inc_b(),
                             Team::B => score.inc_a(),
                         }
                         bank.set_current("explode");
-                        audio.play_sound(*pin_explosion, pin_explosion.volume());
+                        mixer.play_at(AudioBus::Sfx, pin_explosion, constants.pan(pin_pos.x), 1.0);
+                        rumble.play_team(&play_mode, *team, root.rumble.pin_explosion);
+                        shake.start(root.screen_shake);
                     }
                 }
             }
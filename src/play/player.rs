@@ -14,56 +14,80 @@ pub mod state {
 
 pub const SPREAD: f32 = 45.;
 
-#[derive(HasSchema, Clone, Copy, Default, PartialEq, Eq, Debug)]
+#[derive(HasSchema, Clone, Copy, Default, PartialEq, Eq, Hash, Debug)]
 #[repr(u8)]
 pub enum Team {
     #[default]
     A,
     B,
 }
+impl Team {
+    pub fn variants() -> [Team; 2] {
+        [Team::A, Team::B]
+    }
+    pub fn other(self) -> Team {
+        match self {
+            Team::A => Team::B,
+            Team::B => Team::A,
+        }
+    }
+}
 
+/// A character on the court, by team and its place in the team.
 #[derive(HasSchema, Clone, Default, Copy, PartialEq, Eq, Hash, Debug)]
-pub enum PlayerSlot {
-    #[default]
-    A1,
-    A2,
-    B1,
-    B2,
+#[repr(C)]
+pub struct PlayerSlot {
+    pub team: Team,
+    /// The place in the team, from `0` up to [`PlayerSlot::TEAM_SIZE`].
+    pub n: u8,
 }
 impl PlayerSlot {
+    /// The most characters a team can have.
+    pub const TEAM_SIZE: usize = 4;
+    /// The most characters in a match.
+    pub const COUNT: usize = Self::TEAM_SIZE * 2;
+
+    pub fn new(team: Team, n: usize) -> Self {
+        debug_assert!(n < Self::TEAM_SIZE, "player slot out of range");
+        Self { team, n: n as u8 }
+    }
     /// The associated offline client index to this slot.
     pub fn index(&self) -> usize {
-        match self {
-            PlayerSlot::A1 => 0,
-            PlayerSlot::A2 => 1,
-            PlayerSlot::B1 => 2,
-            PlayerSlot::B2 => 3,
-        }
+        let offset = match self.team {
+            Team::A => 0,
+            Team::B => Self::TEAM_SIZE,
+        };
+        offset + self.n as usize
     }
-    pub fn variants() -> [PlayerSlot; 4] {
-        use PlayerSlot::*;
-        [A1, A2, B1, B2]
+    pub fn variants() -> Vec<PlayerSlot> {
+        Team::variants()
+            .into_iter()
+            .flat_map(Self::team_variants)
+            .collect()
     }
+    pub fn team_variants(team: Team) -> impl Iterator<Item = PlayerSlot> {
+        (0..Self::TEAM_SIZE).map(move |n| Self::new(team, n))
+    }
+    /// The slot a dual stick player controls with their other stick.
     pub fn partner(&self) -> PlayerSlot {
-        use PlayerSlot::*;
-        match self {
-            A1 => A2,
-            A2 => A1,
-            B1 => B2,
-            B2 => B1,
+        Self {
+            n: self.n ^ 1,
+            ..*self
         }
     }
     pub fn team(&self) -> Team {
-        match self {
-            PlayerSlot::A1 | PlayerSlot::A2 => Team::A,
-            PlayerSlot::B1 | PlayerSlot::B2 => Team::B,
-        }
+        self.team
     }
     pub fn is_primary(&self) -> bool {
-        matches!(self, Self::A1 | Self::B1)
+        self.n % 2 == 0
     }
     pub fn is_secondary(&self) -> bool {
-        matches!(self, Self::A2 | Self::B2)
+        !self.is_primary()
+    }
+    /// The outfit worn in the slot after picking `picked` outfits past its own,
+    /// so teammates and dual stick partners start out in different ones.
+    pub fn outfit(&self, picked: usize) -> usize {
+        self.n as usize + picked
     }
 }
 
@@ -111,7 +135,7 @@ impl Player {
 }
 impl Default for Player {
     fn default() -> Self {
-        Self::new(PlayerSlot::A1)
+        Self::new(PlayerSlot::default())
     }
 }
 pub fn plugin(session: &mut SessionBuilder) {
@@ -212,29 +236,79 @@ fn turn_transition(world: &World) {
         }
     }
 }
+/// Picks the teammate to pass to, favoring the ones close by & in line with where the
+/// player is aiming, or the one closest to the aim if nobody is in front.
+fn pass_target(start: Vec2, aim: Vec2, teammates: &[(Entity, Vec2)]) -> Option<(Entity, Vec2)> {
+    let angle_to = |end: Vec2| aim.angle_between(end - start).abs();
+    // Someone twice as far away is as good as someone 60 degrees off to the side.
+    let cost = |end: Vec2| end.distance(start) / angle_to(end).cos();
+
+    teammates
+        .iter()
+        .filter(|(_, end)| angle_to(*end) < 90_f32.to_radians())
+        .min_by(|(_, a), (_, b)| cost(*a).total_cmp(&cost(*b)))
+        .or_else(|| {
+            teammates
+                .iter()
+                .min_by(|(_, a), (_, b)| angle_to(*a).total_cmp(&angle_to(*b)))
+        })
+        .copied()
+}
+
+/// Where a teammate at `pos` running with `velocity` will be when a pass from `start` gets there.
+fn lead_position(start: Vec2, pos: Vec2, velocity: Vec2, constants: &Constants) -> Vec2 {
+    let Constants {
+        kick_power,
+        turn_frames,
+        recieve_frames,
+        pass_lead,
+        player_bounds,
+        player_radius,
+        ..
+    } = *constants;
+
+    // The ball is only kicked after turning, then travels at the kick power.
+    let frames = turn_frames as f32 + pos.distance(start) / kick_power.max(0.01);
+    // The teammate stands still to recieve, and only runs on if the ball takes longer.
+    let running = (frames - recieve_frames as f32).max(0.0);
+    let bounds = player_bounds - Vec2::splat(player_radius);
+
+    (pos + velocity * running * pass_lead).clamp(-bounds, bounds)
+}
+
+/// The movement of a running player from their stick.
+fn run_velocity(control: &PlayInput, speed: f32) -> Vec2 {
+    Vec2::new(control.x, control.y).clamp_length_max(1.0) * speed
+}
+
 fn turn_out_transition(
     In(player_e): In<Entity>,
     entities: Res<Entities>,
     root: Root<Data>,
+    constants: Res<Constants>,
     players: Comp<Player>,
-    mut audio: ResMut<AudioCenter>,
+    transforms: Comp<Transform>,
+    mut mixer: ResMut<AudioMixer>,
     mut balls: CompMut<Ball>,
     mut states: CompMut<State>,
 ) {
     let state = states.get_mut(player_e).unwrap();
 
-    if state.age() >= root.constant.turn_frames {
+    if state.age() >= constants.turn_frames {
         state.current = state::kick();
 
-        let (_ball_e, ball) = entities.get_single_with(&mut balls).unwrap();
         let player = players.get(player_e).unwrap();
 
         // TODO: Add warn if the dribble_pos didn't get to the target position by now.
 
-        ball.owner = Maybe::Unset;
-        ball.velocity = player.angle * root.constant.kick_power;
+        if let Some(ball_e) = ball::owned_by(&entities, &balls, player_e) {
+            let ball = balls.get_mut(ball_e).unwrap();
+            ball.owner = Maybe::Unset;
+            ball.velocity = player.angle * constants.kick_power;
+        }
         let Sounds { ball_kicked, .. } = root.sound;
-        audio.play_sound(*ball_kicked, ball_kicked.volume());
+        let x = transforms.get(player_e).unwrap().translation.x;
+        mixer.play_at(AudioBus::Sfx, ball_kicked, constants.pan(x), 1.0);
     }
 }
 
@@ -242,7 +316,7 @@ fn to_ball_transition(
     In(player_e): In<Entity>,
     entities: Res<Entities>,
     transforms: Comp<Transform>,
-    root: Root<Data>,
+    constants: Res<Constants>,
     mut players: CompMut<Player>,
     mut states: CompMut<State>,
     mut balls: CompMut<Ball>,
@@ -255,15 +329,22 @@ fn to_ball_transition(
         player_radius,
         ball_radius,
         ..
-    } = root.constant;
-
-    let (ball_e, ball) = entities.get_single_with(&mut balls).unwrap();
-    let ball_pos = transforms.get(ball_e).unwrap().translation.xy();
+    } = *constants;
+
+    // in case they gain the ball by other means we want to switch to dribbling
+    let target = ball::owned_by(&entities, &balls, player_e).or_else(|| {
+        ball::nearest_free(
+            &entities,
+            &balls,
+            &transforms,
+            player_pos,
+            player_radius + ball_radius,
+        )
+    });
 
-    if ball.owner.is_none() && ball_pos.distance(player_pos) <= player_radius + ball_radius
-        // in case they gain the ball by other means we want to switch to dribbling
-        || ball.owner.option().is_some_and(|target| target == player_e)
-    {
+    if let Some(ball_e) = target {
+        let ball = balls.get_mut(ball_e).unwrap();
+        let ball_pos = transforms.get(ball_e).unwrap().translation.xy();
         player.angle = (ball_pos - player_pos).normalize_or_zero();
 
         ball.velocity = default();
@@ -275,36 +356,64 @@ fn to_ball_transition(
 
 fn ball_out_transition(
     In(player_e): In<Entity>,
-    inputs: Res<PlayInputs>,
+    mut inputs: ResMut<PlayInputs>,
     player_ent_signs: Res<PlayerEntSigns>,
+    root: Root<Data>,
+    constants: Res<Constants>,
     clients: Comp<Client>,
+    boosts: Comp<Boosts>,
     transforms: CompMut<Transform>,
     mut players: CompMut<Player>,
     mut states: CompMut<State>,
 ) {
     let client = clients.get(player_e).unwrap();
-    let control = inputs.get_control(client.index);
+    let control = *inputs.get_control(client.index);
 
     // pass
-    if control.pass.just_pressed() {
-        let partner_e = player_ent_signs.partner(player_e);
-        let partner_state = states.get_mut(partner_e).unwrap();
-
-        if partner_state.current == state::free() {
-            partner_state.current = state::recieve();
-
+    // The press stays buffered until a teammate is free to recieve it.
+    if control.pass.buffered() {
+        let player = players.get(player_e).unwrap();
+        let start = transforms.get(player_e).unwrap().translation.xy();
+        let stick = Vec2::new(control.x, control.y);
+        let aim = if stick.length() > 0.0 {
+            stick.normalize_or_zero()
+        } else {
+            player.angle
+        };
+        let free_teammates = player_ent_signs
+            .teammates(player_e)
+            .into_iter()
+            .filter(|teammate_e| states.get(*teammate_e).unwrap().current == state::free())
+            .map(|teammate_e| {
+                let pos = transforms.get(teammate_e).unwrap().translation.xy();
+                let control = inputs.get_control(clients.get(teammate_e).unwrap().index);
+                let mut speed = constants.run_speed;
+                if boosts
+                    .get(teammate_e)
+                    .is_some_and(|boosts| boosts.speed > 0)
+                {
+                    speed *= root.power_up.speed_factor;
+                }
+                let lead = lead_position(start, pos, run_velocity(control, speed), &constants);
+                (teammate_e, lead)
+            })
+            .collect::<Vec<_>>();
+
+        if let Some((target_e, end)) = pass_target(start, aim, &free_teammates) {
+            inputs.get_control_mut(client.index).pass.consume();
+            states.get_mut(target_e).unwrap().current = state::recieve();
             states.get_mut(player_e).unwrap().current = state::turn();
 
-            let start = transforms.get(player_e).unwrap().translation.xy();
-            let end = transforms.get(partner_e).unwrap().translation.xy();
-            let direction = (end - start).normalize();
+            let direction = (end - start).normalize_or_zero();
 
             players.get_mut(player_e).unwrap().angle = direction;
-            players.get_mut(partner_e).unwrap().angle = -direction;
+            players.get_mut(target_e).unwrap().angle = -direction;
+            // A shoot buffered in the same step waits for the next time they have the ball.
+            return;
         }
     }
     // shoot
-    if control.shoot.just_pressed() {
+    if inputs.get_control_mut(client.index).shoot.consume() {
         states.get_mut(player_e).unwrap().current = state::shoot();
 
         let player = players.get_mut(player_e).unwrap();
@@ -335,11 +444,20 @@ fn to_tackled_transition(
     transforms: Comp<Transform>,
     players: Comp<Player>,
     root: Root<Data>,
-    mut audio: ResMut<AudioCenter>,
+    constants: Res<Constants>,
+    play_mode: Res<PlayMode>,
+    mut mixer: ResMut<AudioMixer>,
+    mut rumble: ResMut<Rumble>,
+    boosts: Comp<Boosts>,
     mut balls: CompMut<Ball>,
     mut states: CompMut<State>,
 ) {
-    let (_ball_e, ball) = entities.get_single_with(&mut balls).unwrap();
+    if boosts
+        .get(player_e)
+        .is_some_and(|boosts| boosts.immunity > 0)
+    {
+        return;
+    }
     let player = players.get(player_e).unwrap();
     let pos = transforms.get(player_e).unwrap().translation.xy();
     let Sounds { player_tackled, .. } = root.sound;
@@ -351,16 +469,22 @@ fn to_tackled_transition(
 
         if tackler_state == state::tackle()
             && tackler.team() != player.team()
-            && tackler_pos.distance(pos) <= root.constant.player_radius * 2.
+            && tackler_pos.distance(pos) <= constants.player_radius * 2.
         {
             states.get_mut(player_e).unwrap().current = state::tackled();
 
-            audio.play_sound(*player_tackled, player_tackled.volume());
-
-            if let Maybe::Set(target) = &mut ball.owner {
-                if *target == player_e {
-                    *target = tackler_e;
-                }
+            mixer.play_at(AudioBus::Sfx, player_tackled, constants.pan(pos.x), 1.0);
+            rumble.play_slot(&play_mode, player.id, root.rumble.tackled);
+
+            if let Some(ball_e) = ball::owned_by(&entities, &balls, player_e) {
+                // A tackler can only dribble one ball, so any other is knocked loose.
+                let tackler_has_ball = ball::owned_by(&entities, &balls, tackler_e).is_some();
+                let ball = balls.get_mut(ball_e).unwrap();
+                ball.owner = if tackler_has_ball {
+                    Maybe::Unset
+                } else {
+                    Maybe::Set(tackler_e)
+                };
             }
         }
     }
@@ -368,23 +492,26 @@ fn to_tackled_transition(
 
 fn to_tackle_transition(
     In(player_e): In<Entity>,
-    inputs: Res<PlayInputs>,
+    mut inputs: ResMut<PlayInputs>,
     clients: Comp<Client>,
     root: Root<Data>,
-    mut audio: ResMut<AudioCenter>,
+    constants: Res<Constants>,
+    transforms: Comp<Transform>,
+    mut mixer: ResMut<AudioMixer>,
     mut players: CompMut<Player>,
     mut states: CompMut<State>,
 ) {
     let player = players.get_mut(player_e).unwrap();
     let state = states.get_mut(player_e).unwrap();
     let client = clients.get(player_e).unwrap();
-    let control = inputs.get_control(client.index);
+    let control = inputs.get_control_mut(client.index);
     let Sounds { player_tackle, .. } = root.sound;
 
-    if control.pass.just_pressed() {
+    if control.pass.consume() {
         state.current = state::tackle();
         player.action_angle = player.angle;
-        audio.play_sound(*player_tackle, player_tackle.volume());
+        let x = transforms.get(player_e).unwrap().translation.x;
+        mixer.play_at(AudioBus::Sfx, player_tackle, constants.pan(x), 1.0);
     }
 }
 
@@ -394,10 +521,15 @@ fn shoot_out_transition(
     inputs: Res<PlayInputs>,
     clients: Comp<Client>,
     root: Root<Data>,
-    mut audio: ResMut<AudioCenter>,
+    constants: Res<Constants>,
+    play_mode: Res<PlayMode>,
+    transforms: Comp<Transform>,
+    mut mixer: ResMut<AudioMixer>,
+    mut rumble: ResMut<Rumble>,
     mut players: CompMut<Player>,
     mut states: CompMut<State>,
     mut balls: CompMut<Ball>,
+    mut boosts: CompMut<Boosts>,
 ) {
     let player = players.get_mut(player_e).unwrap();
     let state = states.get_mut(player_e).unwrap();
@@ -407,15 +539,20 @@ fn shoot_out_transition(
     if !control.shoot.pressed() {
         state.current = state::kick();
 
-        let (_ball_e, ball) = entities.get_single_with(&mut balls).unwrap();
-        if let Maybe::Set(target) = ball.owner {
-            if target == player_e {
-                ball.owner = Maybe::Unset;
-                ball.velocity = player.angle * root.constant.kick_power;
-            }
+        if let Some(ball_e) = ball::owned_by(&entities, &balls, player_e) {
+            let power = boosts
+                .get_mut(player_e)
+                .map_or(constants.kick_power, |boosts| {
+                    boosts.shot_power(constants.kick_power, root.power_up.mega_kick_factor)
+                });
+            let ball = balls.get_mut(ball_e).unwrap();
+            ball.owner = Maybe::Unset;
+            ball.velocity = player.angle * power;
+            rumble.play_slot(&play_mode, player.id, root.rumble.kick);
         }
         let Sounds { ball_kicked, .. } = root.sound;
-        audio.play_sound(*ball_kicked, ball_kicked.volume());
+        let x = transforms.get(player_e).unwrap().translation.x;
+        mixer.play_at(AudioBus::Sfx, ball_kicked, constants.pan(x), 1.0);
     }
 }
 
@@ -457,10 +594,12 @@ fn shoot_update(
 
         let direction = Vec2::new(control.x, control.y);
 
-        if direction.length() > 0.2 {
+        if direction.length() > 0.0 {
+            // Pushing the stick partway turns the aim slower, for finer aiming.
+            let turn = 0.05 * direction.length().min(1.0);
             let direction = direction.normalize();
-            let range_clock = Vec2::new(1.0, 0.05).normalize_or_zero();
-            let range_count = Vec2::new(1.0, -0.05).normalize_or_zero();
+            let range_clock = Vec2::new(1.0, turn).normalize_or_zero();
+            let range_count = Vec2::new(1.0, -turn).normalize_or_zero();
 
             if direction.angle_between(player.angle) < range_clock.angle_between(Vec2::X) {
                 player.angle = player.angle.rotate(range_clock)
@@ -488,14 +627,13 @@ fn tackle_update(world: &World) {
             continue;
         }
         {
-            let asset_server = world.asset_server();
             let Constants {
                 tackle_friction,
                 tackle_speed,
                 player_radius,
                 ball_radius,
                 ..
-            } = asset_server.root::<Data>().constant;
+            } = *world.resource::<Constants>();
 
             let entities = world.resource::<Entities>();
             let states = world.component::<State>();
@@ -516,13 +654,18 @@ fn tackle_update(world: &World) {
 
                 let player_pos = transform.translation.xy();
 
-                let (ball_e, ball) = entities.get_single_with(&mut balls).unwrap();
-                let ball_pos = transforms.get(ball_e).unwrap().translation.xy();
-
-                if ball.owner.is_none()
-                    && ball_pos.distance(player_pos) <= player_radius + ball_radius
-                    || ball.owner.option().is_some_and(|target| target == player_e)
-                {
+                let target = ball::owned_by(&entities, &balls, player_e).or_else(|| {
+                    ball::nearest_free(
+                        &entities,
+                        &balls,
+                        &transforms,
+                        player_pos,
+                        player_radius + ball_radius,
+                    )
+                });
+
+                if let Some(ball_e) = target {
+                    let ball = balls.get_mut(ball_e).unwrap();
                     // Don't point at the ball like normal.
                     // player.angle = (ball_pos - player_pos).normalize_or_zero();
 
@@ -539,8 +682,7 @@ fn tackle_update(world: &World) {
 
 fn walk(In(player_e): In<Entity>, world: &World) {
     {
-        let asset_server = world.asset_server();
-        let root = asset_server.root::<Data>();
+        let constants = world.resource::<Constants>();
         let inputs = world.resource_mut::<PlayInputs>();
         let clients = world.component_mut::<Client>();
         let mut players = world.component_mut::<Player>();
@@ -552,20 +694,30 @@ fn walk(In(player_e): In<Entity>, world: &World) {
         let transform = transforms.get_mut(player_e).unwrap();
         let client = clients.get(player_e).unwrap();
 
-        let speed = match state.current {
-            id if id == state::free() => root.constant.run_speed,
-            id if id == state::ball() => root.constant.dribble_speed,
+        let mut speed = match state.current {
+            id if id == state::free() => constants.run_speed,
+            id if id == state::ball() => constants.dribble_speed,
             _ => return,
         };
+        if world
+            .component::<Boosts>()
+            .get(player_e)
+            .is_some_and(|boosts| boosts.speed > 0)
+        {
+            speed *= world.asset_server().root::<Data>().power_up.speed_factor;
+        }
         let control = inputs.get_control(client.index);
+        // The stick is already zero inside the controller's deadzone,
+        // and how far it's pushed follows the controller's response curve.
         let direction = Vec2::new(control.x, control.y);
 
-        if direction.length() > 0.2 {
+        if direction.length() > 0.0 {
             player.animation = ustr("walk");
             player.angle = direction.normalize_or_zero();
 
-            transform.translation.x += player.angle.x * speed;
-            transform.translation.y += player.angle.y * speed;
+            let velocity = run_velocity(control, speed);
+            transform.translation.x += velocity.x;
+            transform.translation.y += velocity.y;
         } else {
             player.animation = ustr("idle");
         }
@@ -577,12 +729,14 @@ fn walk(In(player_e): In<Entity>, world: &World) {
 fn update_player_collisions(
     In(player_e): In<Entity>,
     player_ent_signs: Res<PlayerEntSigns>,
-    root: Root<Data>,
+    constants: Res<Constants>,
     mut transforms: CompMut<Transform>,
 ) {
-    let player_positions: [(Entity, Vec2); 4] = player_ent_signs
+    let player_positions: Vec<(Entity, Vec2)> = player_ent_signs
         .entities()
-        .map(|entity| (entity, transforms.get(entity).unwrap().translation.xy()));
+        .into_iter()
+        .map(|entity| (entity, transforms.get(entity).unwrap().translation.xy()))
+        .collect();
 
     let transform = transforms.get_mut(player_e).unwrap();
 
@@ -592,7 +746,7 @@ fn update_player_collisions(
             continue;
         }
         // collision detection
-        let touch = root.constant.player_radius * 2.;
+        let touch = constants.player_radius * 2.;
         let distance = transform.translation.xy().distance(pos);
         if distance < touch {
             let overlap = touch - distance;
@@ -606,16 +760,30 @@ fn update_player_collisions(
 
 fn update_bounds_collisions(
     In(player_e): In<Entity>,
-    root: Root<Data>,
+    entities: Res<Entities>,
+    constants: Res<Constants>,
+    obstacles: Comp<Obstacle>,
     mut transforms: CompMut<Transform>,
 ) {
+    let placed_obstacles = obstacle::placed(&entities, &obstacles, &transforms);
     let transform = transforms.get_mut(player_e).unwrap();
 
     let Constants {
         player_bounds,
         player_radius,
         ..
-    } = root.constant;
+    } = *constants;
+
+    for (obstacle, obstacle_transform) in placed_obstacles {
+        if let Some((normal, depth)) = obstacle.penetration(
+            &obstacle_transform,
+            transform.translation.xy(),
+            player_radius,
+        ) {
+            transform.translation.x += normal.x * depth;
+            transform.translation.y += normal.y * depth;
+        }
+    }
 
     let bounds = player_bounds;
     let x_padding = player_radius;
@@ -744,7 +912,7 @@ fn hide_stick_indicators(
 fn player_graphics(
     entities: Res<Entities>,
     states: Comp<State>,
-    root: Root<Data>,
+    constants: Res<Constants>,
     mut path2ds: CompMut<Path2d>,
     mut players: CompMut<Player>,
 ) {
@@ -756,7 +924,7 @@ fn player_graphics(
         } else if player.angle.x < 0.0 {
             player.flip_x = true
         }
-        *path.points.get_mut(10).unwrap() = player.angle * root.constant.player_radius;
+        *path.points.get_mut(10).unwrap() = player.angle * constants.player_radius;
 
         match state.current {
             s if s == state::free() => path.color = path2d::color::FREE,
@@ -805,3 +973,89 @@ fn player_graphics(
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn teammates(positions: &[Vec2]) -> Vec<(Entity, Vec2)> {
+        let mut entities = Entities::default();
+        positions
+            .iter()
+            .map(|pos| (entities.create(), *pos))
+            .collect()
+    }
+
+    fn constants() -> Constants {
+        Constants {
+            kick_power: 5.,
+            turn_frames: 10,
+            recieve_frames: 30,
+            pass_lead: 1.,
+            player_bounds: Vec2::new(100., 50.),
+            player_radius: 5.,
+            ..default()
+        }
+    }
+
+    #[test]
+    fn nobody_to_pass_to() {
+        assert_eq!(pass_target(Vec2::ZERO, Vec2::X, &[]), None);
+    }
+
+    #[test]
+    fn pass_prefers_ahead_over_closer_off_to_the_side() {
+        let team = teammates(&[Vec2::new(5., 20.), Vec2::new(30., 0.)]);
+        assert_eq!(pass_target(Vec2::ZERO, Vec2::X, &team), Some(team[1]));
+    }
+
+    #[test]
+    fn pass_never_weighs_teammates_at_or_behind_90_degrees() {
+        // Their cosine is zero or negative, which would make them the cheapest.
+        let team = teammates(&[Vec2::new(-5., 0.), Vec2::new(0., 5.), Vec2::new(40., 39.)]);
+        assert_eq!(pass_target(Vec2::ZERO, Vec2::X, &team), Some(team[2]));
+    }
+
+    #[test]
+    fn pass_behind_falls_back_to_the_smallest_angle() {
+        let team = teammates(&[Vec2::new(-5., 0.), Vec2::new(-1., -20.)]);
+        assert_eq!(pass_target(Vec2::ZERO, Vec2::X, &team), Some(team[1]));
+    }
+
+    #[test]
+    fn standing_teammate_isnt_led() {
+        let pos = Vec2::new(50., 0.);
+        assert_eq!(
+            lead_position(Vec2::ZERO, pos, Vec2::ZERO, &constants()),
+            pos
+        );
+    }
+
+    #[test]
+    fn lead_skips_the_recieve_frames() {
+        let velocity = Vec2::new(0., 1.);
+        // 10 turn frames and 10 in the air land within the 30 they stand to recieve.
+        let near = Vec2::new(50., 0.);
+        assert_eq!(
+            lead_position(Vec2::ZERO, near, velocity, &constants()),
+            near
+        );
+        // 10 turn frames and 30 in the air leaves 10 running.
+        let (start, far) = (Vec2::new(-75., 0.), Vec2::new(75., 0.));
+        assert_eq!(
+            lead_position(start, far, velocity, &constants()),
+            Vec2::new(75., 10.)
+        );
+    }
+
+    #[test]
+    fn lead_stays_on_the_court() {
+        let lead = lead_position(
+            Vec2::ZERO,
+            Vec2::new(90., 40.),
+            Vec2::new(10., 10.),
+            &constants(),
+        );
+        assert_eq!(lead, Vec2::new(95., 45.));
+    }
+}
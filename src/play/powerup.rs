@@ -0,0 +1,259 @@
+use super::*;
+
+pub mod prelude {
+    pub use super::{Boosts, PinShield, PowerUp, PowerUpConstants, PowerUpKind};
+}
+
+#[derive(HasSchema, Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[repr(u8)]
+pub enum PowerUpKind {
+    #[default]
+    SpeedBoost,
+    MegaKick,
+    TackleImmunity,
+    PinShield,
+    MultiBall,
+}
+
+/// An item on the court that gives its effect to the first player to touch it.
+#[derive(HasSchema, Clone, Copy, Default)]
+pub struct PowerUp(pub PowerUpKind);
+
+#[derive(HasSchema, Clone, Default)]
+#[repr(C)]
+pub struct PowerUpConstants {
+    pub radius: f32,
+    /// The frames between each power-up spawning.
+    pub spawn_frames: u64,
+    /// The most power-ups that can be on the court at once.
+    pub max_count: usize,
+    /// The seconds a power-up stays on the court before disappearing.
+    pub lifetime: f32,
+
+    pub speed_frames: u64,
+    pub speed_factor: f32,
+    pub mega_kick_factor: f32,
+    pub immunity_frames: u64,
+    pub shield_frames: u64,
+}
+
+/// The power-up effects active on a player.
+#[derive(HasSchema, Clone, Copy, Default)]
+pub struct Boosts {
+    /// The frames left of running faster.
+    pub speed: u64,
+    /// Whether the next shot is a mega kick.
+    pub mega_kick: bool,
+    /// The frames left that the player can't be tackled.
+    pub immunity: u64,
+}
+impl Boosts {
+    /// Uses up the mega kick, returning the power to shoot with.
+    pub fn shot_power(&mut self, kick_power: f32, mega_kick_factor: f32) -> f32 {
+        if std::mem::take(&mut self.mega_kick) {
+            kick_power * mega_kick_factor
+        } else {
+            kick_power
+        }
+    }
+}
+
+/// The frames left that each team's pins can't be knocked down.
+#[derive(HasSchema, Clone, Copy, Default)]
+pub struct PinShield {
+    pub a: u64,
+    pub b: u64,
+}
+impl PinShield {
+    pub fn get(&self, team: Team) -> u64 {
+        match team {
+            Team::A => self.a,
+            Team::B => self.b,
+        }
+    }
+    pub fn set(&mut self, team: Team, frames: u64) {
+        match team {
+            Team::A => self.a = frames,
+            Team::B => self.b = frames,
+        }
+    }
+    pub fn is_shielded(&self, team: Team) -> bool {
+        self.get(team) > 0
+    }
+}
+
+/// Counts down to the next power-up and decides where it spawns.
+#[derive(HasSchema, Clone, Default)]
+pub struct PowerUpSpawner {
+    pub frames: u64,
+    pub rng: SeededRng,
+}
+
+pub fn plugin(session: &mut SessionBuilder) {
+    session.init_resource::<PinShield>();
+    session.init_resource::<PowerUpSpawner>();
+    session.add_startup_system(
+        |rules: Res<MatchRules>, mut spawner: ResMut<PowerUpSpawner>| {
+            spawner.rng = SeededRng::new(rules.seed);
+        },
+    );
+    session.add_system_to_stage(Update, spawn_power_ups);
+    session.add_system_to_stage(Update, collect_power_ups);
+    session.add_system_to_stage(Update, tick_boosts);
+    session.add_system_to_stage(PostUpdate, boost_graphics);
+}
+
+fn spawn_power_ups(world: &World) {
+    let kinds = world.resource::<MatchRules>().power_up_kinds();
+    if kinds.is_empty() || !matches!(*world.resource::<PlayState>(), PlayState::WaitForScore) {
+        return;
+    }
+    let PowerUpConstants {
+        radius,
+        spawn_frames,
+        max_count,
+        ..
+    } = world.asset_server().root::<Data>().power_up;
+
+    {
+        let mut spawner = world.resource_mut::<PowerUpSpawner>();
+        spawner.frames += 1;
+        if spawner.frames < spawn_frames {
+            return;
+        }
+        spawner.frames = 0;
+    }
+
+    let spawn = {
+        let entities = world.resource::<Entities>();
+        let power_ups = world.component::<PowerUp>();
+        if entities.iter_with(&power_ups).count() >= max_count {
+            return;
+        }
+        let obstacles = world.component::<Obstacle>();
+        let transforms = world.component::<Transform>();
+        let placed_obstacles = obstacle::placed(&entities, &obstacles, &transforms);
+        let bounds = world.resource::<Constants>().player_bounds - Vec2::splat(radius);
+        let mut spawner = world.resource_mut::<PowerUpSpawner>();
+
+        let kind = kinds[spawner.rng.index(kinds.len())];
+        // Give up for this round if there's no free space after a few tries.
+        (0..8)
+            .map(|_| {
+                Vec2::new(
+                    spawner.rng.range(-bounds.x, bounds.x),
+                    spawner.rng.range(-bounds.y, bounds.y),
+                )
+            })
+            .find(|pos| {
+                placed_obstacles.iter().all(|(obstacle, transform)| {
+                    obstacle.penetration(transform, *pos, radius).is_none()
+                })
+            })
+            .map(|pos| (kind, pos))
+    };
+    if let Some((kind, pos)) = spawn {
+        spawn::power_up(world, kind, pos);
+    }
+}
+
+fn collect_power_ups(
+    entities: Res<Entities>,
+    root: Root<Data>,
+    constants: Res<Constants>,
+    players: Comp<Player>,
+    states: Comp<State>,
+    power_ups: Comp<PowerUp>,
+    transforms: Comp<Transform>,
+    mut boosts: CompMut<Boosts>,
+    mut shield: ResMut<PinShield>,
+    mut mixer: ResMut<AudioMixer>,
+    mut commands: Commands,
+) {
+    let PowerUpConstants {
+        radius,
+        speed_frames,
+        immunity_frames,
+        shield_frames,
+        ..
+    } = root.power_up;
+    let Sounds { power_up, .. } = root.sound;
+
+    for (power_up_e, PowerUp(kind)) in entities.iter_with(&power_ups) {
+        let pos = transforms.get(power_up_e).unwrap().translation.xy();
+
+        for (player_e, (player, state, boosts)) in
+            entities.iter_with((&players, &states, &mut boosts))
+        {
+            if [state::wait(), state::win(), state::lose()].contains(&state.current) {
+                continue;
+            }
+            let player_pos = transforms.get(player_e).unwrap().translation.xy();
+            if player_pos.distance(pos) > constants.player_radius + radius {
+                continue;
+            }
+            match kind {
+                PowerUpKind::SpeedBoost => boosts.speed = speed_frames,
+                PowerUpKind::MegaKick => boosts.mega_kick = true,
+                PowerUpKind::TackleImmunity => boosts.immunity = immunity_frames,
+                PowerUpKind::PinShield => shield.set(player.team(), shield_frames),
+                PowerUpKind::MultiBall => commands.add(move |world: &World| {
+                    let ball_e = spawn::ball(world, pos);
+                    world.component_mut::<ExtraBall>().insert(ball_e, ExtraBall);
+                }),
+            }
+            mixer.play_at(AudioBus::Sfx, power_up, constants.pan(pos.x), 1.0);
+            commands.add(move |mut entities: ResMut<Entities>| entities.kill(power_up_e));
+            break;
+        }
+    }
+}
+
+fn tick_boosts(
+    entities: Res<Entities>,
+    mut boosts: CompMut<Boosts>,
+    mut shield: ResMut<PinShield>,
+) {
+    for (_player_e, boosts) in entities.iter_with(&mut boosts) {
+        boosts.speed = boosts.speed.saturating_sub(1);
+        boosts.immunity = boosts.immunity.saturating_sub(1);
+    }
+    shield.a = shield.a.saturating_sub(1);
+    shield.b = shield.b.saturating_sub(1);
+}
+
+/// Tints players with their active boosts & shielded pins.
+fn boost_graphics(
+    entities: Res<Entities>,
+    shield: Res<PinShield>,
+    boosts: Comp<Boosts>,
+    follows: Comp<Follow>,
+    player_sprites: Comp<PlayerSprite>,
+    pins: Comp<Pin>,
+    teams: Comp<Team>,
+    mut atlases: CompMut<AtlasSprite>,
+) {
+    for (_e, (_sprite, follow, atlas)) in
+        entities.iter_with((&player_sprites, &follows, &mut atlases))
+    {
+        let Some(boosts) = boosts.get(follow.target()) else {
+            continue;
+        };
+        atlas.color = if boosts.immunity > 0 {
+            Color::CYAN
+        } else if boosts.mega_kick {
+            Color::ORANGE
+        } else if boosts.speed > 0 {
+            Color::YELLOW
+        } else {
+            Color::WHITE
+        };
+    }
+    for (_e, (_pin, team, atlas)) in entities.iter_with((&pins, &teams, &mut atlases)) {
+        atlas.color = if shield.is_shielded(*team) {
+            Color::GREEN
+        } else {
+            Color::WHITE
+        };
+    }
+}
@@ -0,0 +1,300 @@
+//! Applies changes to the root [`Data`] asset to a running match.
+//!
+//! Most systems read the [`Constants`] resource & [`Data::sound`] every frame and
+//! pick up changes on their own, but anything that was copied into components
+//! when the scene was spawned needs to be refreshed here.
+//!
+//! Changes to [`Data::constant`] or the match's [`ArenaAsset`] are copied into the
+//! [`Constants`] resource, overriding any edits made to it at runtime.
+use super::*;
+
+/// The data that the scene was last built from.
+#[derive(HasSchema, Clone, Default)]
+pub struct LoadedData {
+    /// The last seen [`Data::constant`], used to tell when the asset changes.
+    pub asset_constant: Constants,
+    /// The last seen [`ArenaAsset`] of the match.
+    pub arena: ArenaAsset,
+    /// The last applied [`Constants`] resource.
+    pub constant: Constants,
+    pub sprite: Sprites,
+    pub sound: Sounds,
+}
+
+pub fn plugin(session: &mut SessionBuilder) {
+    session.init_resource::<LoadedData>();
+    session.add_startup_system(
+        |asset_server: Res<AssetServer>,
+         arena: Res<Arena>,
+         constants: Res<Constants>,
+         mut loaded: ResMut<LoadedData>| {
+            let root = asset_server.root::<Data>();
+            *loaded = LoadedData {
+                asset_constant: root.constant.clone(),
+                arena: asset_server.get(arena.0).clone(),
+                constant: constants.clone(),
+                sprite: root.sprite.clone(),
+                sound: root.sound.clone(),
+            };
+        },
+    );
+    session.add_system_to_stage(First, update);
+}
+
+pub fn update(world: &World) {
+    let (asset_constant, arena, sprite, sound) = {
+        let asset_server = world.asset_server();
+        let root = asset_server.root::<Data>();
+        (
+            root.constant.clone(),
+            asset_server.get(world.resource::<Arena>().0).clone(),
+            root.sprite.clone(),
+            root.sound.clone(),
+        )
+    };
+    let previous = world.resource::<LoadedData>().clone();
+
+    if previous.asset_constant != asset_constant || previous.arena != arena {
+        tracing::info!("constants or arena asset changed, overriding constants");
+        let mut constant = asset_constant.clone();
+        arena.apply(&mut constant);
+        *world.resource_mut::<Constants>() = constant;
+    }
+    if previous.arena.court != arena.court {
+        tracing::info!("court changed, refreshing court");
+        world.run_system(refresh_court, ());
+    }
+    if previous.arena.obstacles != arena.obstacles {
+        tracing::info!("obstacles changed, respawning obstacles");
+        refresh_obstacles(world);
+    }
+    let constant = world.resource::<Constants>().clone();
+
+    if previous.constant != constant {
+        tracing::info!("constants changed, refreshing play scene");
+        world.run_system(refresh_paths, ());
+        world.run_system(refresh_pins, previous.constant.clone());
+        refresh_sprite_offsets(world);
+    }
+    if previous.sprite != sprite {
+        tracing::info!("sprites changed, refreshing play scene");
+        world.run_system(refresh_sprites, ());
+        refresh_sprite_offsets(world);
+    }
+    if previous.sound != sound {
+        tracing::info!("sounds changed, refreshing play scene");
+        world.run_system(refresh_sounds, ());
+    }
+    *world.resource_mut::<LoadedData>() = LoadedData {
+        asset_constant,
+        arena,
+        constant,
+        sprite,
+        sound,
+    };
+}
+
+fn refresh_paths(
+    entities: Res<Entities>,
+    constants: Res<Constants>,
+    courts: Comp<Court>,
+    players: Comp<Player>,
+    balls: Comp<Ball>,
+    pins: Comp<Pin>,
+    mut path2ds: CompMut<Path2d>,
+) {
+    for (_e, (_court, path)) in entities.iter_with((&courts, &mut path2ds)) {
+        *path = path2d::bounds(&constants);
+    }
+    for (_e, (_player, path)) in entities.iter_with((&players, &mut path2ds)) {
+        *path = path2d::player(&constants);
+    }
+    for (_e, (_ball, path)) in entities.iter_with((&balls, &mut path2ds)) {
+        *path = path2d::ball(&constants);
+    }
+    for (_e, (_pin, path)) in entities.iter_with((&pins, &mut path2ds)) {
+        *path = path2d::pin(&constants);
+    }
+}
+
+fn refresh_court(
+    entities: Res<Entities>,
+    asset_server: Res<AssetServer>,
+    arena: Res<Arena>,
+    courts: Comp<Court>,
+    mut sprites: CompMut<Sprite>,
+) {
+    let court = asset_server.get(arena.0).court;
+    for (_e, (_court, sprite)) in entities.iter_with((&courts, &mut sprites)) {
+        sprite.image = *court;
+    }
+}
+
+fn refresh_obstacles(world: &World) {
+    let dead = {
+        let entities = world.resource::<Entities>();
+        let obstacles = world.component::<Obstacle>();
+        let obstacle_sprites = world.component::<ObstacleSprite>();
+        entities
+            .iter_with(&obstacles)
+            .map(|(obstacle_e, _obstacle)| obstacle_e)
+            .chain(
+                entities
+                    .iter_with(&obstacle_sprites)
+                    .map(|(sprite_e, _sprite)| sprite_e),
+            )
+            .collect::<Vec<_>>()
+    };
+    for obstacle_e in dead {
+        world.resource_mut::<Entities>().kill(obstacle_e);
+    }
+    spawn::obstacles(world);
+}
+
+/// Moves the remaining pins to their place in the new layout,
+/// removing or adding pins if [`Constants::pin_count`] changed.
+fn refresh_pins(In(previous): In<Constants>, world: &World) {
+    let court = world
+        .asset_server()
+        .get(world.resource::<Arena>().0)
+        .court
+        .size();
+    let constant = world.resource::<Constants>().clone();
+
+    let mut dead = Vec::new();
+    {
+        let entities = world.resource::<Entities>();
+        let pins = world.component::<Pin>();
+        let teams = world.component::<Team>();
+        let banks = world.component::<AnimationBankSprite>();
+        let mut transforms = world.component_mut::<Transform>();
+
+        for (pin_e, (_pin, team, bank)) in entities.iter_with((&pins, &teams, &banks)) {
+            if bank.current != ustr("still") {
+                continue;
+            }
+            let transform = transforms.get_mut(pin_e).unwrap();
+            let pos = transform.translation.xy();
+            let n = (0..previous.pin_count)
+                .min_by(|a, b| {
+                    let a = spawn::pin_position(*team, *a, &previous, court).distance(pos);
+                    let b = spawn::pin_position(*team, *b, &previous, court).distance(pos);
+                    a.total_cmp(&b)
+                })
+                .unwrap_or_default();
+
+            if n < constant.pin_count {
+                let new_pos = spawn::pin_position(*team, n, &constant, court);
+                transform.translation.x = new_pos.x;
+                transform.translation.y = new_pos.y;
+            } else {
+                dead.push(pin_e);
+            }
+        }
+    }
+    for pin_e in dead {
+        world.resource_mut::<Entities>().kill(pin_e);
+    }
+    for n in previous.pin_count..constant.pin_count {
+        spawn::pin(world, Team::A, n);
+        spawn::pin(world, Team::B, n);
+    }
+}
+
+fn refresh_sprite_offsets(world: &World) {
+    let sprite_offset = spawn::player_sprite_offset(world);
+    let entities = world.resource::<Entities>();
+    let player_sprites = world.component::<PlayerSprite>();
+    let mut follows = world.component_mut::<Follow>();
+
+    for (_e, (_sprite, follow)) in entities.iter_with((&player_sprites, &mut follows)) {
+        if let Follow::XY { offset, .. } = follow {
+            offset.y = sprite_offset;
+        }
+    }
+}
+
+fn refresh_sprites(
+    entities: Res<Entities>,
+    asset_server: Res<AssetServer>,
+    mode: Res<PlayMode>,
+    players: Comp<Player>,
+    follows: Comp<Follow>,
+    player_sprites: Comp<PlayerSprite>,
+    shadows: Comp<PlayerShadowSprite>,
+    indicators: Comp<StickIndicator>,
+    aim_arrows: Comp<AimArrow>,
+    aim_cones: Comp<AimCone>,
+    balls: Comp<Ball>,
+    pins: Comp<Pin>,
+    teams: Comp<Team>,
+    obstacles: Comp<Obstacle>,
+    obstacle_sprites: Comp<ObstacleSprite>,
+    power_ups: Comp<PowerUp>,
+    mut atlases: CompMut<AtlasSprite>,
+    mut banks: CompMut<AnimationBankSprite>,
+    mut sprites: CompMut<Sprite>,
+    mut transforms: CompMut<Transform>,
+) {
+    let root = asset_server.root::<Data>();
+    let animations = asset_server.get(root.sprite.player_animations);
+
+    for (_e, (_sprite, follow, atlas, bank)) in
+        entities.iter_with((&player_sprites, &follows, &mut atlases, &mut banks))
+    {
+        let Some(player) = players.get(follow.target()) else {
+            continue;
+        };
+        atlas.atlas = root
+            .sprite
+            .player_atlas(mode.team_color(player.team()), mode.outfit(player.id));
+        *bank = animations.to_bank(bank.current);
+    }
+    for (_e, (_shadow, follow, sprite)) in entities.iter_with((&shadows, &follows, &mut sprites)) {
+        let Some(player) = players.get(follow.target()) else {
+            continue;
+        };
+        sprite.image = root
+            .sprite
+            .team_color(mode.team_color(player.team()))
+            .shadow;
+    }
+    for (_e, (_indicator, follow, sprite)) in
+        entities.iter_with((&indicators, &follows, &mut sprites))
+    {
+        let Some(player) = players.get(follow.target()) else {
+            continue;
+        };
+        sprite.image = root.sprite.stick_indicator(player.id);
+    }
+    for (_e, (_arrow, sprite)) in entities.iter_with((&aim_arrows, &mut sprites)) {
+        sprite.image = root.sprite.aim_arrow;
+    }
+    for (_e, (_cone, sprite)) in entities.iter_with((&aim_cones, &mut sprites)) {
+        sprite.image = root.sprite.aim_cone;
+    }
+    for (_e, (_ball, atlas)) in entities.iter_with((&balls, &mut atlases)) {
+        atlas.atlas = root.sprite.ball;
+    }
+    for (_e, (_pin, team, atlas)) in entities.iter_with((&pins, &teams, &mut atlases)) {
+        atlas.atlas = root.sprite.team_color(mode.team_color(*team)).pin;
+    }
+    for (_e, (_sprite, follow, sprite, transform)) in
+        entities.iter_with((&obstacle_sprites, &follows, &mut sprites, &mut transforms))
+    {
+        let Some(obstacle) = obstacles.get(follow.target()) else {
+            continue;
+        };
+        sprite.image = root.sprite.obstacle(obstacle);
+        transform.scale = (obstacle.extents() / root.sprite.obstacle_image_size).extend(1.);
+    }
+    for (_e, (PowerUp(kind), sprite)) in entities.iter_with((&power_ups, &mut sprites)) {
+        sprite.image = root.sprite.power_up(*kind);
+    }
+}
+
+fn refresh_sounds(entities: Res<Entities>, root: Root<Data>, mut balls: CompMut<Ball>) {
+    for (_e, ball) in entities.iter_with(&mut balls) {
+        ball.sound_timer = Timer::from_seconds(root.sound.ball_spin_buffer, TimerMode::Repeating);
+    }
+}
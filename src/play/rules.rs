@@ -0,0 +1,57 @@
+use super::*;
+
+pub mod prelude {
+    pub use super::MatchRules;
+}
+
+/// Optional rules chosen before a match.
+///
+/// The defaults are read from [`Data::rules`].
+#[derive(HasSchema, Clone, Debug, Default, PartialEq)]
+#[repr(C)]
+pub struct MatchRules {
+    /// How many balls are on the court at the start of each round.
+    pub ball_count: usize,
+    /// Spawns power-ups on the court during the match.
+    pub power_ups: bool,
+    pub speed_boost: bool,
+    pub mega_kick: bool,
+    pub tackle_immunity: bool,
+    pub pin_shield: bool,
+    pub multi_ball: bool,
+    /// Decides where & which power-ups spawn, so the same seed always plays out the same.
+    ///
+    /// A new one is rolled for every match with [`Self::reseeded`].
+    pub seed: u64,
+}
+impl MatchRules {
+    /// The most balls that can be chosen for a round.
+    pub const MAX_BALL_COUNT: usize = 4;
+
+    /// These rules with a freshly rolled [`Self::seed`], for starting a new match.
+    pub fn reseeded(&self) -> Self {
+        Self {
+            seed: getrandom::u64().unwrap_or_else(|err| {
+                tracing::warn!("couldn't roll a match seed, using the last one: {err}");
+                self.seed
+            }),
+            ..self.clone()
+        }
+    }
+    /// The power-ups that can spawn during the match.
+    pub fn power_up_kinds(&self) -> Vec<PowerUpKind> {
+        if !self.power_ups {
+            return Vec::new();
+        }
+        [
+            (self.speed_boost, PowerUpKind::SpeedBoost),
+            (self.mega_kick, PowerUpKind::MegaKick),
+            (self.tackle_immunity, PowerUpKind::TackleImmunity),
+            (self.pin_shield, PowerUpKind::PinShield),
+            (self.multi_ball, PowerUpKind::MultiBall),
+        ]
+        .into_iter()
+        .filter_map(|(enabled, kind)| enabled.then_some(kind))
+        .collect()
+    }
+}
@@ -0,0 +1,59 @@
+use super::*;
+
+pub mod prelude {
+    pub use super::{ScreenShake, ScreenShakeMeta};
+}
+
+#[derive(HasSchema, Clone, Copy, Default, Debug, PartialEq)]
+#[repr(C)]
+pub struct ScreenShakeMeta {
+    /// How far the camera moves at the start of the shake.
+    pub offset: f32,
+    pub seconds: f32,
+    /// How many times a second the camera sways.
+    pub frequency: f32,
+}
+
+/// Shakes the camera for a moment, unless it's turned off in the [`Settings`].
+#[derive(HasSchema, Clone, Default)]
+pub struct ScreenShake {
+    pub meta: ScreenShakeMeta,
+    /// The seconds left until the camera is still again.
+    pub remaining: f32,
+}
+impl ScreenShake {
+    pub fn start(&mut self, meta: ScreenShakeMeta) {
+        self.meta = meta;
+        self.remaining = meta.seconds;
+    }
+}
+
+pub fn plugin(session: &mut SessionBuilder) {
+    session.init_resource::<ScreenShake>();
+    session.add_system_to_stage(PostUpdate, update);
+}
+
+fn update(
+    entities: Res<Entities>,
+    time: Res<Time>,
+    settings: Res<Settings>,
+    cameras: Comp<Camera>,
+    mut shake: ResMut<ScreenShake>,
+    mut transforms: CompMut<Transform>,
+) {
+    shake.remaining = (shake.remaining - time.delta_seconds()).max(0.0);
+
+    let offset = if settings.screen_shake && shake.meta.seconds > 0.0 {
+        let sway = time.elapsed_seconds() * shake.meta.frequency * std::f32::consts::TAU;
+        // Different speeds on each axis so the camera doesn't just move along a line.
+        Vec2::new(sway.sin(), (sway * 1.3).cos())
+            * shake.meta.offset
+            * (shake.remaining / shake.meta.seconds)
+    } else {
+        Vec2::ZERO
+    };
+    for (_camera_e, (_camera, transform)) in entities.iter_with((&cameras, &mut transforms)) {
+        transform.translation.x = offset.x;
+        transform.translation.y = offset.y;
+    }
+}
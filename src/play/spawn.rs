@@ -1,16 +1,23 @@
 use super::*;
 
 pub mod prelude {
+    pub use super::ball as spawn_ball;
     pub use super::new_player_transform;
+    pub use super::pin as spawn_pin;
     pub use super::pins as spawn_pins;
     pub use super::player as spawn_player;
     pub use super::scene as spawn_scene;
 }
 
+/// Marker for the court entity holding the court sprite and bounds outlines.
+#[derive(HasSchema, Clone, Default)]
+pub struct Court;
+
 pub fn scene(world: &World) {
     let asset_server = world.asset_server();
-    let root = asset_server.root::<Data>();
-    let screen = root.court.size();
+    let arena = asset_server.get(world.resource::<Arena>().0);
+    let constant = world.resource::<Constants>().clone();
+    let screen = arena.court.size();
 
     // Camera
     world
@@ -24,128 +31,237 @@ pub fn scene(world: &World) {
     // Court
     world
         .spawn()
-        .insert(path2d::bounds(&root))
+        .insert(Court)
+        .insert(path2d::bounds(&constant))
         .insert(Sprite {
-            image: *root.court,
+            image: *arena.court,
             ..Default::default()
         })
         .insert(Transform::from_z(layers::COURT));
 
-    // Ball
+    // Balls
+    let ball_count = world.resource::<MatchRules>().ball_count;
+    for pos in ball::start_positions(ball_count, 0., &constant) {
+        self::ball(world, pos);
+    }
+
+    // Players
+    let players = match &*world.resource::<PlayMode>() {
+        PlayMode::Online { .. } => todo!(),
+        PlayMode::Offline(players) => players.clone(),
+    };
+    let mut ent_signs = PlayerEntSigns::default();
+    for team in Team::variants() {
+        let characters = players.team(team).characters();
+        let team_size = characters.len();
+        for (place, info) in characters.into_iter().enumerate() {
+            let player_e = self::player(world, info, place, team_size);
+            match team {
+                Team::A => ent_signs.team_a.push(player_e),
+                Team::B => ent_signs.team_b.push(player_e),
+            }
+        }
+    }
+    world.resources.insert(ent_signs);
+
+    // Pins
+    world.run_system(self::pins, ());
+
+    // Obstacles
+    self::obstacles(world);
+}
+
+pub fn ball(world: &World, pos: Vec2) -> Entity {
+    let asset_server = world.asset_server();
+    let root = asset_server.root::<Data>();
+    let constant = world.resource::<Constants>().clone();
+
     world
         .spawn()
         .insert(Ball {
             sound_timer: Timer::from_seconds(root.sound.ball_spin_buffer, TimerMode::Repeating),
             ..Default::default()
         })
-        .insert(path2d::ball(&root))
+        .insert(path2d::ball(&constant))
         .insert(AtlasSprite::new(root.sprite.ball))
         .insert(ball::sprite())
-        .insert(Transform::from_translation(Vec3::new(0., 0., layers::BALL)));
+        .insert(Interpolated::default())
+        .insert(Transform::from_translation(Vec3::new(
+            pos.x,
+            pos.y,
+            layers::BALL,
+        )))
+        .id()
+}
 
-    // Players
-    let ent_signs = match &*world.resource::<PlayMode>() {
-        PlayMode::Online { .. } => todo!(),
-        PlayMode::Offline(PlayersInfo { team_a, team_b }) => PlayerEntSigns {
-            a1: self::player(world, team_a.primary()),
-            a2: self::player(world, team_a.secondary()),
-            b1: self::player(world, team_b.primary()),
-            b2: self::player(world, team_b.secondary()),
-        },
+pub fn obstacles(world: &World) {
+    let asset_server = world.asset_server();
+    let arena = asset_server.get(world.resource::<Arena>().0);
+
+    for meta in arena.obstacles.iter() {
+        self::obstacle(world, *meta);
+    }
+}
+
+pub fn obstacle(world: &World, meta: ObstacleMeta) -> Entity {
+    let sprite = world.asset_server().root::<Data>().sprite.clone();
+    let obstacle = Obstacle::from(meta);
+    let transform = Transform {
+        translation: Vec3::new(meta.pos.x, meta.pos.y, layers::OBSTACLE),
+        rotation: Quat::from_rotation_z(meta.rotation.to_radians()),
+        ..default()
     };
-    world.resources.insert(ent_signs);
 
-    // Pins
-    world.run_system(self::pins, ());
+    let obstacle_e = world
+        .spawn()
+        .insert(obstacle)
+        .insert(path2d::obstacle(&obstacle))
+        .insert(transform)
+        .id();
+    world
+        .spawn()
+        .insert(ObstacleSprite)
+        .insert(Sprite {
+            image: sprite.obstacle(&obstacle),
+            ..Default::default()
+        })
+        .insert(Follow::XYZ {
+            target: obstacle_e,
+            offset: Vec3::ZERO,
+        })
+        .insert(Transform {
+            scale: (obstacle.extents() / sprite.obstacle_image_size).extend(1.),
+            ..transform
+        });
+    obstacle_e
 }
 
-pub fn pins(world: &World, root: Root<Data>) {
+pub fn pins(world: &World, constants: Res<Constants>) {
+    for n in 0..constants.pin_count {
+        self::pin(world, Team::A, n);
+        self::pin(world, Team::B, n);
+    }
+}
+
+/// The position of the `n`th pin of the `team` for a court of the given size.
+pub fn pin_position(team: Team, n: usize, constant: &Constants, court: Vec2) -> Vec2 {
     let Constants {
         pin_count,
         pin_padding,
         pin_radius,
         ..
-    } = root.constant;
-    let Sprites { a_pin, b_pin, .. } = root.sprite;
-    let screen = root.court.size();
-    let screen_bounds = screen / 2.;
-    let shift = (screen.y - pin_padding.y * 2.) / pin_count as f32;
+    } = *constant;
+    let screen_bounds = court / 2.;
+    let shift = (court.y - pin_padding.y * 2.) / pin_count.max(1) as f32;
 
-    for n in 0..pin_count {
-        let x_padding = pin_radius + pin_padding.x;
-        let y = -screen_bounds.y + pin_padding.y + pin_radius * 2. + (shift * n as f32);
+    let x_padding = pin_radius + pin_padding.x;
+    let y = -screen_bounds.y + pin_padding.y + pin_radius * 2. + (shift * n as f32);
 
-        world
-            .spawn()
-            .insert(Pin)
-            .insert(Team::A)
-            .insert(AtlasSprite::new(a_pin))
-            .insert(pin::animation_bank())
-            .insert(path2d::pin(&root))
-            .insert(Transform::from_translation(Vec3::new(
-                -screen_bounds.x + x_padding,
-                y,
-                layers::PIN,
-            )))
-            .id();
-
-        world
-            .spawn()
-            .insert(Pin)
-            .insert(Team::B)
-            .insert(AtlasSprite::new(b_pin))
-            .insert(pin::animation_bank())
-            .insert(path2d::pin(&root))
-            .insert(Transform::from_translation(Vec3::new(
-                screen_bounds.x - x_padding,
-                y,
-                layers::PIN,
-            )))
-            .id();
+    match team {
+        Team::A => Vec2::new(-screen_bounds.x + x_padding, y),
+        Team::B => Vec2::new(screen_bounds.x - x_padding, y),
     }
 }
 
-pub fn new_player_transform(player_id: PlayerSlot, root: &Data) -> Transform {
-    let bounds = root.constant.player_bounds;
-    let mut pos = Vec2::new(bounds.x / 2., bounds.y / 2.);
+pub fn pin(world: &World, team: Team, n: usize) -> Entity {
+    let asset_server = world.asset_server();
+    let root = asset_server.root::<Data>();
+    let arena = asset_server.get(world.resource::<Arena>().0);
+    let constant = world.resource::<Constants>().clone();
+    let pos = pin_position(team, n, &constant, arena.court.size());
+    let color = world.resource::<PlayMode>().team_color(team);
 
-    match player_id {
-        PlayerSlot::A1 => pos *= vec2(-1., 1.),
-        PlayerSlot::A2 => pos *= vec2(-1., -1.),
-        PlayerSlot::B1 => (),
-        PlayerSlot::B2 => pos *= vec2(1., -1.),
-    }
+    world
+        .spawn()
+        .insert(Pin)
+        .insert(team)
+        .insert(AtlasSprite::new(root.sprite.team_color(color).pin))
+        .insert(pin::animation_bank())
+        .insert(path2d::pin(&constant))
+        .insert(Transform::from_translation(Vec3::new(
+            pos.x,
+            pos.y,
+            layers::PIN,
+        )))
+        .id()
+}
+
+pub fn power_up(world: &World, kind: PowerUpKind, pos: Vec2) -> Entity {
+    let (image, constant) = {
+        let asset_server = world.asset_server();
+        let root = asset_server.root::<Data>();
+        (root.sprite.power_up(kind), root.power_up.clone())
+    };
+
+    world
+        .spawn()
+        .insert(PowerUp(kind))
+        .insert(path2d::power_up(constant.radius))
+        .insert(Sprite {
+            image,
+            ..Default::default()
+        })
+        .insert(Lifetime::seconds(constant.lifetime))
+        .insert(Transform::from_translation(Vec3::new(
+            pos.x,
+            pos.y,
+            layers::POWER_UP,
+        )))
+        .id()
+}
+
+/// Where a player starts each round, spread evenly down its team's half of the court.
+pub fn new_player_transform(
+    team: Team,
+    place: usize,
+    team_size: usize,
+    constant: &Constants,
+) -> Transform {
+    let bounds = constant.player_bounds;
+    let x = match team {
+        Team::A => bounds.x / -2.,
+        Team::B => bounds.x / 2.,
+    };
+    // With two players this puts them halfway to the top & bottom bounds.
+    let y = bounds.y * (1. - (place as f32 * 2. + 1.) / team_size.max(1) as f32);
+
+    Transform::from_translation(Vec3::new(x, y, layers::HITO))
+}
 
-    Transform::from_translation(Vec3::new(pos.x, pos.y, layers::HITO))
+/// The vertical offset of the player sprite so the character's feet line up with its transform.
+pub fn player_sprite_offset(world: &World) -> f32 {
+    let asset_server = world.asset_server();
+    let root = asset_server.root::<Data>();
+    let player_radius = world.resource::<Constants>().player_radius;
+    // Every outfit is drawn on the same size of tile.
+    let tile_size = asset_server
+        .get::<Atlas>(root.sprite.player_atlas(0, 0))
+        .tile_size;
+    (tile_size.y / 2.) - player_radius * 2.
 }
 
-pub fn player(world: &World, player: PlayerInfo) -> Entity {
+pub fn player(world: &World, player: PlayerInfo, place: usize, team_size: usize) -> Entity {
     let PlayerInfo {
         number,
         dual_stick,
         slot,
+        picked_outfit,
         ..
     } = player;
     let asset_server = world.asset_server();
     let root = asset_server.root::<Data>();
-    let transform = new_player_transform(slot, &root);
+    let constant = world.resource::<Constants>().clone();
     let team = slot.team();
+    let color = world.resource::<PlayMode>().team_color(team);
+    let transform = new_player_transform(team, place, team_size, &constant);
     let animations = asset_server.get(root.sprite.player_animations);
 
     let Sprites {
-        player_a,
-        player_b,
-        player_a2,
-        player_b2,
         aim_cone,
         aim_arrow,
-        lstick_indicator,
-        rstick_indicator,
         ..
     } = root.sprite;
 
-    let Constants { player_radius, .. } = root.constant;
-
     let mut player = world.spawn();
 
     player
@@ -155,21 +271,20 @@ pub fn player(world: &World, player: PlayerInfo) -> Entity {
         })
         .insert(Player::new(slot))
         .insert(State::new("wait"))
-        .insert(path2d::player(&root));
+        .insert(Boosts::default())
+        .insert(Interpolated::default())
+        .insert(path2d::player(&constant));
 
-    let sprite_offset =
-        (asset_server.get::<Atlas>(player_a).value().tile_size.y / 2.) - player_radius * 2.;
+    let sprite_offset = player_sprite_offset(world);
 
     world
         .spawn()
         .insert(PlayerSprite)
-        .insert(AtlasSprite::new(match slot {
-            PlayerSlot::A1 => player_a,
-            PlayerSlot::A2 => player_a2,
-            PlayerSlot::B1 => player_b,
-            PlayerSlot::B2 => player_b2,
-        }))
+        .insert(AtlasSprite::new(
+            root.sprite.player_atlas(color, slot.outfit(picked_outfit)),
+        ))
         .insert(animations.to_bank(ustr("idle")))
+        .insert(Interpolated::default())
         .insert(Follow::XY {
             target: player.id(),
             offset: Vec2::new(0., sprite_offset),
@@ -180,12 +295,10 @@ pub fn player(world: &World, player: PlayerInfo) -> Entity {
         .spawn()
         .insert(PlayerShadowSprite)
         .insert(Sprite {
-            image: match team {
-                Team::A => root.sprite.p1_shadow,
-                Team::B => root.sprite.p2_shadow,
-            },
+            image: root.sprite.team_color(color).shadow,
             ..Default::default()
         })
+        .insert(Interpolated::default())
         .insert(Follow::XY {
             target: player.id(),
             offset: Vec2::new(0., -4.),
@@ -198,12 +311,10 @@ pub fn player(world: &World, player: PlayerInfo) -> Entity {
             .spawn()
             .insert(StickIndicator)
             .insert(Sprite {
-                image: match slot {
-                    PlayerSlot::A1 | PlayerSlot::B1 => lstick_indicator,
-                    PlayerSlot::A2 | PlayerSlot::B2 => rstick_indicator,
-                },
+                image: root.sprite.stick_indicator(slot),
                 ..Default::default()
             })
+            .insert(Interpolated::default())
             .insert(Follow::XY {
                 target: player.id(),
                 offset: Vec2::new(0., 22.),
@@ -216,6 +327,7 @@ pub fn player(world: &World, player: PlayerInfo) -> Entity {
             image: **root.menu.team_select.player_icons()[number],
             ..Default::default()
         })
+        .insert(Interpolated::default())
         .insert(Follow::XY {
             target: player.id(),
             offset: Vec2::new(0., -18.),
@@ -226,6 +338,7 @@ pub fn player(world: &World, player: PlayerInfo) -> Entity {
     world
         .spawn()
         .insert(AimArrow(player.id()))
+        .insert(Interpolated::default())
         .insert(Sprite {
             color: Color::NONE,
             image: aim_arrow,
@@ -235,6 +348,7 @@ pub fn player(world: &World, player: PlayerInfo) -> Entity {
     world
         .spawn()
         .insert(AimCone(player.id()))
+        .insert(Interpolated::default())
         .insert(Sprite {
             color: Color::NONE,
             image: aim_cone,
@@ -244,3 +358,49 @@ pub fn player(world: &World, player: PlayerInfo) -> Entity {
 
     player.id()
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn constants(pin_count: usize) -> Constants {
+        Constants {
+            pin_count,
+            pin_padding: Vec2::new(11., 2.),
+            pin_radius: 4.,
+            ..default()
+        }
+    }
+
+    #[test]
+    fn pins_are_mirrored_between_teams() {
+        let constant = constants(7);
+        let court = Vec2::new(375., 211.);
+        for n in 0..7 {
+            let a = pin_position(Team::A, n, &constant, court);
+            let b = pin_position(Team::B, n, &constant, court);
+            assert_eq!(a.x, -b.x);
+            assert_eq!(a.y, b.y);
+        }
+    }
+
+    #[test]
+    fn pins_stay_on_the_court() {
+        let constant = constants(7);
+        let court = Vec2::new(375., 211.);
+        let bounds = court / 2.;
+        let mut last = f32::NEG_INFINITY;
+        for n in 0..7 {
+            let pos = pin_position(Team::A, n, &constant, court);
+            assert!(pos.x.abs() <= bounds.x && pos.y.abs() <= bounds.y, "{pos}");
+            assert!(pos.y > last);
+            last = pos.y;
+        }
+    }
+
+    #[test]
+    fn no_pins_doesnt_divide_by_zero() {
+        let pos = pin_position(Team::A, 0, &constants(0), Vec2::new(375., 211.));
+        assert!(pos.is_finite());
+    }
+}
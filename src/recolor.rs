@@ -0,0 +1,85 @@
+use super::*;
+
+use std::collections::HashMap;
+use std::path::{Component, Path, PathBuf};
+
+/// An image drawn in one palette with its colors swapped for another's, so each team color
+/// doesn't need its own copy of the sprites. It's loaded from a yaml `*.recolor` file like:
+///
+/// ```yaml
+/// image: redSoccerPlayer.png
+/// from: ../palettes/red.palette.yaml
+/// to: ../palettes/green.palette.yaml
+/// ```
+///
+/// The paths are relative to the file, and it loads as an [`Image`], so it can be used
+/// anywhere an image can, like the image of an atlas.
+#[derive(HasSchema, Clone, Default)]
+#[type_data(asset_loader(["recolor"], RecolorLoader))]
+#[repr(C)]
+pub struct RecolorAsset;
+
+struct RecolorLoader;
+impl AssetLoader for RecolorLoader {
+    fn load(&self, ctx: AssetLoadCtx, bytes: &[u8]) -> BoxedFuture<anyhow::Result<SchemaBox>> {
+        let bytes = bytes.to_vec();
+        Box::pin(async move {
+            let mut file: HashMap<String, String> = serde_yaml::from_slice(&bytes)?;
+            let mut path = |key: &str| {
+                file.remove(key)
+                    .ok_or_else(|| anyhow::anyhow!("recolor is missing `{key}`"))
+            };
+            let (image_path, from_path, to_path) = (path("image")?, path("from")?, path("to")?);
+            let from = load_palette(&ctx, &from_path).await?;
+            let to = load_palette(&ctx, &to_path).await?;
+            anyhow::ensure!(
+                from.len() == to.len(),
+                "palettes `{from_path}` and `{to_path}` have a different number of colors"
+            );
+            let swaps = from.into_iter().zip(to).collect::<HashMap<_, _>>();
+
+            let mut image =
+                image::load_from_memory(&load_relative(&ctx, &image_path).await?)?.into_rgba8();
+            for pixel in image.pixels_mut() {
+                let [r, g, b, a] = pixel.0;
+                if let Some(&[r, g, b]) = swaps.get(&[r, g, b]) {
+                    pixel.0 = [r, g, b, a];
+                }
+            }
+            Ok(SchemaBox::new(Image::Data(image.into())))
+        })
+    }
+}
+
+/// Reads the `colors` of the palette at `path`, a list of colors with each channel from 0 to 255.
+/// Every palette lists the matching colors in the same order.
+async fn load_palette(ctx: &AssetLoadCtx, path: &str) -> anyhow::Result<Vec<[u8; 3]>> {
+    let mut palette: HashMap<String, Vec<[u8; 3]>> =
+        serde_yaml::from_slice(&load_relative(ctx, path).await?)?;
+    palette
+        .remove("colors")
+        .ok_or_else(|| anyhow::anyhow!("palette `{path}` has no `colors`"))
+}
+
+/// Reads the file at `path` relative to the asset being loaded, from the same pack.
+async fn load_relative(ctx: &AssetLoadCtx, path: &str) -> anyhow::Result<Vec<u8>> {
+    let path = ctx.loc.path.parent().unwrap_or(Path::new("")).join(path);
+    // The asset io doesn't resolve `..`, so it's done here.
+    let mut normalized = PathBuf::new();
+    for component in path.components() {
+        match component {
+            Component::ParentDir => {
+                normalized.pop();
+            }
+            Component::CurDir => {}
+            component => normalized.push(component),
+        }
+    }
+    ctx.asset_server
+        .io
+        .load_file(AssetLocRef {
+            path: &normalized,
+            pack: ctx.loc.pack.as_deref(),
+        })
+        .await
+}
@@ -0,0 +1,93 @@
+use super::*;
+
+pub struct RumbleGamePlugin;
+impl GamePlugin for RumbleGamePlugin {
+    fn install(self, game: &mut Game) {
+        game.insert_shared_resource(Rumble::default());
+        game.systems.add_after_system(Rumble::update);
+    }
+}
+
+#[derive(HasSchema, Clone, Copy, Default, Debug, PartialEq)]
+#[repr(C)]
+pub struct RumbleMeta {
+    /// How hard to rumble, from `0.0` to `1.0`.
+    pub strength: f32,
+    pub seconds: f32,
+}
+
+#[derive(HasSchema, Clone, Default, PartialEq)]
+#[repr(C)]
+pub struct RumbleAssets {
+    pub join: RumbleMeta,
+    pub tackled: RumbleMeta,
+    pub kick: RumbleMeta,
+    pub pin_explosion: RumbleMeta,
+    pub win: RumbleMeta,
+}
+
+#[derive(HasSchema, Clone, Copy, Default, Debug)]
+pub struct RumbleRequest {
+    pub gamepad: u32,
+    pub rumble: RumbleMeta,
+}
+
+/// The rumbles requested by gameplay, played on the gamepads at the end of the frame.
+#[derive(HasSchema, Clone)]
+pub struct Rumble {
+    /// Turns all rumble on or off.
+    pub enabled: bool,
+    pub requests: SVec<RumbleRequest>,
+}
+impl Default for Rumble {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            requests: default(),
+        }
+    }
+}
+impl Rumble {
+    pub fn play(&mut self, gamepad: u32, rumble: RumbleMeta) {
+        // The touch controls have nothing to rumble.
+        if self.enabled && rumble.strength > 0.0 && gamepad != TOUCH_GAMEPAD {
+            self.requests.push(RumbleRequest { gamepad, rumble });
+        }
+    }
+    /// Rumbles the gamepad controlling the character in `slot`.
+    pub fn play_slot(&mut self, mode: &PlayMode, slot: PlayerSlot, rumble: RumbleMeta) {
+        if let Some(gamepad) = mode.gamepad(slot) {
+            self.play(gamepad, rumble);
+        }
+    }
+    /// Rumbles every gamepad on the `team`.
+    pub fn play_team(&mut self, mode: &PlayMode, team: Team, rumble: RumbleMeta) {
+        let PlayMode::Offline(players) = mode else {
+            return;
+        };
+        for player in players.team(team).players.iter() {
+            self.play(player.gamepad, rumble);
+        }
+    }
+    pub fn update(game: &mut Game) {
+        let requests = std::mem::take(&mut game.shared_resource_mut::<Rumble>().unwrap().requests);
+        let local_inputs = game.shared_resource::<LocalInputs>().unwrap();
+        let mut gamepads_rumble = game.shared_resource_mut::<GamepadsRumble>().unwrap();
+
+        for RumbleRequest { gamepad, rumble } in requests.iter().copied() {
+            let intensity = local_inputs
+                .gamepads
+                .get(&gamepad)
+                .map_or(1.0, |input| input.profile.rumble);
+            let strength = (rumble.strength * intensity).clamp(0.0, 1.0);
+            gamepads_rumble.set_rumble(
+                gamepad,
+                GamepadRumbleIntensity {
+                    strong_motor: strength,
+                    weak_motor: strength,
+                },
+                rumble.seconds,
+            );
+        }
+    }
+}
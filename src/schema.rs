@@ -2,6 +2,10 @@ use super::*;
 
 pub fn register_schemas() {
     Data::register_schema();
+    ArenaAsset::register_schema();
+    RecolorAsset::register_schema();
+    Settings::register_schema();
+    InputProfiles::register_schema();
     LocalizationAsset::register_schema();
     FluentBundleAsset::register_schema();
     FluentResourceAsset::register_schema();
@@ -8,13 +8,13 @@ pub const PLAY_PRIORITY: i32 = 1;
 
 // NOTE: session creation may need to have an immediate and delayed command versions for each session
 pub trait SessionCreation {
-    fn create_play(&mut self, mode: PlayMode);
+    fn create_play(&mut self, mode: PlayMode, arena: Handle<ArenaAsset>, rules: MatchRules);
     fn delete_play(&mut self);
 }
 impl SessionCreation for Sessions {
-    fn create_play(&mut self, mode: PlayMode) {
+    fn create_play(&mut self, mode: PlayMode, arena: Handle<ArenaAsset>, rules: MatchRules) {
         self.add_command(Box::new(move |sessions| {
-            sessions.create_with(PLAY, PlayPlugin { mode });
+            sessions.create_with(PLAY, PlayPlugin { mode, arena, rules });
         }));
     }
     fn delete_play(&mut self) {
@@ -24,36 +24,167 @@ impl SessionCreation for Sessions {
     }
 }
 
+/// Lets the UI and dev tools control how the [`OfflineRunner`] advances the simulation.
+///
+/// The simulation always advances in fixed steps of [`OfflineRunner::STEP`],
+/// these settings only change how often those steps are run.
+#[derive(HasSchema, Clone)]
+pub struct RunnerControl {
+    /// Stops the simulation from advancing on its own.
+    pub paused: bool,
+    /// The number of single frames to run while [`Self::paused`].
+    pub steps: u32,
+    /// How fast the simulation runs relative to real time.
+    ///
+    /// Use [`Self::set_time_scale`] to keep it within
+    /// [`Self::MIN_TIME_SCALE`] & [`Self::MAX_TIME_SCALE`].
+    pub time_scale: f64,
+}
+impl Default for RunnerControl {
+    fn default() -> Self {
+        Self {
+            paused: false,
+            steps: 0,
+            time_scale: 1.0,
+        }
+    }
+}
+impl RunnerControl {
+    pub const MIN_TIME_SCALE: f64 = 0.25;
+    pub const MAX_TIME_SCALE: f64 = 2.0;
+
+    pub fn set_time_scale(&mut self, time_scale: f64) {
+        self.time_scale = time_scale.clamp(Self::MIN_TIME_SCALE, Self::MAX_TIME_SCALE);
+    }
+    /// Halves the time scale.
+    pub fn slower(&mut self) {
+        self.set_time_scale(self.time_scale / 2.0);
+    }
+    /// Doubles the time scale.
+    pub fn faster(&mut self) {
+        self.set_time_scale(self.time_scale * 2.0);
+    }
+    pub fn toggle_pause(&mut self) {
+        self.paused = !self.paused;
+    }
+    /// Queues a single frame to be run while paused.
+    pub fn step(&mut self) {
+        if self.paused {
+            self.steps += 1;
+        }
+    }
+}
+
+/// Information about the simulation written by the [`OfflineRunner`].
+#[derive(HasSchema, Clone)]
+pub struct RunnerStatus {
+    /// The number of fixed steps that have been run.
+    pub frame: u64,
+    /// The number of fixed steps run on the last rendered frame.
+    pub steps: u32,
+    /// How far the accumulated time is between the last fixed step and the next,
+    /// from `0.0` to `1.0`. Used for blending rendered motion between steps.
+    pub alpha: f32,
+}
+impl Default for RunnerStatus {
+    fn default() -> Self {
+        Self {
+            frame: 0,
+            steps: 0,
+            alpha: 1.0,
+        }
+    }
+}
+
 #[derive(Default)]
 pub struct OfflineRunner {
     pub accumulator: f64,
     pub last_run: Option<Instant>,
     pub disable_local_input: bool,
+    /// Presses seen on rendered frames that no fixed step has taken yet.
+    pub latched: PlayInputs,
+}
+impl OfflineRunner {
+    pub const STEP: f64 = 1.0 / 60.;
+    /// The most fixed steps run for a single rendered frame.
+    ///
+    /// When the game falls further behind than this, the extra time is dropped
+    /// so slow frames can't snowball into even slower frames.
+    pub const MAX_STEPS: u32 = 5;
+
+    fn run_step(
+        &mut self,
+        world: &mut World,
+        stages: &mut SystemStages,
+        frame_inputs: &PlayInputs,
+    ) {
+        world
+            .resource_mut::<Time>()
+            .advance_exact(std::time::Duration::from_secs_f64(Self::STEP));
+
+        let mut inputs = frame_inputs.clone();
+        let previous = world.resource::<PlayInputs>().clone();
+        inputs.step_from(&previous, &std::mem::take(&mut self.latched));
+        let buffer_frames = world.resource::<Constants>().input_buffer_frames;
+        inputs.buffer_from(&previous, buffer_frames);
+        *world.resource_mut::<PlayInputs>() = inputs;
+        stages.run(world);
+        world.run_system(record_interpolated, ());
+
+        world.resource_mut::<RunnerStatus>().frame += 1;
+    }
 }
 impl SessionRunner for OfflineRunner {
     fn step(&mut self, frame_start: Instant, world: &mut World, stages: &mut SystemStages) {
-        pub const STEP: f64 = 1.0 / 60.;
-
         let last_run = self.last_run.unwrap_or(frame_start);
         let delta = (frame_start - last_run).as_secs_f64();
 
-        self.accumulator += delta;
-
-        if self.accumulator >= STEP {
-            self.accumulator -= STEP;
+        let (paused, steps) = {
+            let mut control = world.resource_mut::<RunnerControl>();
+            if control.paused {
+                // Don't build up time to catch up on after unpausing.
+                self.accumulator = 0.0;
+                let steps = control.steps.min(1);
+                control.steps -= steps;
+                (true, steps)
+            } else {
+                control.steps = 0;
+                self.accumulator += delta
+                    * control
+                        .time_scale
+                        .clamp(RunnerControl::MIN_TIME_SCALE, RunnerControl::MAX_TIME_SCALE);
+                self.accumulator = self.accumulator.min(Self::STEP * Self::MAX_STEPS as f64);
 
-            world
-                .resource_mut::<Time>()
-                .advance_exact(std::time::Duration::from_secs_f64(STEP));
+                let steps = (self.accumulator / Self::STEP) as u32;
+                self.accumulator -= Self::STEP * steps as f64;
+                (false, steps)
+            }
+        };
+        // Local inputs only advance once per rendered frame, so presses are latched
+        // until a step takes them, however many steps this frame runs.
+        let frame_inputs = if self.disable_local_input {
+            PlayInputs::default()
+        } else {
+            PlayInputs::from_world(world)
+        };
+        self.latched.latch(&frame_inputs);
 
-            *world.resource_mut::<PlayInputs>() = if self.disable_local_input {
-                PlayInputs::default()
-            } else {
-                PlayInputs::from_world(world)
-            };
-            stages.run(world);
+        world.run_system(restore_interpolated, ());
+        for _ in 0..steps {
+            self.run_step(world, stages, &frame_inputs);
         }
 
+        let alpha = if paused {
+            1.0
+        } else {
+            (self.accumulator / Self::STEP).clamp(0.0, 1.0) as f32
+        };
+        world.run_system(apply_interpolated, alpha);
+
+        let mut status = world.resource_mut::<RunnerStatus>();
+        status.steps = steps;
+        status.alpha = alpha;
+
         self.last_run = Some(frame_start);
     }
 
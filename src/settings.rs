@@ -0,0 +1,76 @@
+use super::*;
+
+/// The player's preferences, kept between runs in bones' [`Storage`].
+#[derive(HasSchema, Clone, Debug, PartialEq)]
+#[repr(C)]
+pub struct Settings {
+    pub master_volume: f64,
+    pub music_volume: f64,
+    pub effects_volume: f64,
+    pub fullscreen: bool,
+    /// Draws the game at a whole multiple of its pixel size, or fits it to the window when `0`.
+    pub window_scale: u32,
+    pub screen_shake: bool,
+    pub rumble: bool,
+    /// The rules arena select starts with, [`Data::rules`] when unset.
+    pub rules: Maybe<MatchRules>,
+    /// The [`LanguageMeta::code`] of the language menus are shown in, the default when empty.
+    pub language: String,
+}
+impl Default for Settings {
+    fn default() -> Self {
+        Self {
+            master_volume: 1.0,
+            music_volume: 1.0,
+            effects_volume: 1.0,
+            fullscreen: false,
+            window_scale: 0,
+            screen_shake: true,
+            rumble: true,
+            rules: Maybe::Unset,
+            language: String::new(),
+        }
+    }
+}
+impl Settings {
+    pub const MAX_WINDOW_SCALE: u32 = 4;
+    pub const VOLUME_STEPS: f64 = 10.0;
+
+    pub fn load(storage: &Storage) -> Self {
+        storage.get::<Settings>().cloned().unwrap_or_default()
+    }
+    pub fn save(&self, storage: &mut Storage) {
+        storage.insert(self.clone());
+        storage.save();
+    }
+    pub fn rules(&self, root: &Data) -> MatchRules {
+        match &self.rules {
+            Maybe::Set(rules) => rules.clone(),
+            Maybe::Unset => root.rules.clone(),
+        }
+    }
+    pub fn locale(&self, root: &Data) -> Handle<LocalizationAsset> {
+        root.locale(&self.language)
+    }
+    /// Moves a volume up or down by `steps` tenths.
+    pub fn step_volume(volume: f64, steps: i32) -> f64 {
+        ((volume * Self::VOLUME_STEPS).round() + steps as f64).clamp(0.0, Self::VOLUME_STEPS)
+            / Self::VOLUME_STEPS
+    }
+    /// The scale to draw the game at for a window of `size`.
+    pub fn scale(&self, size: Vec2, screen_size: Vec2) -> f32 {
+        let fit = (size.y / screen_size.y).min(size.x / screen_size.x);
+        if self.window_scale == 0 {
+            fit
+        } else {
+            (self.window_scale as f32).min(fit)
+        }
+    }
+    /// Hands the settings to the window & rumble resources that use them.
+    ///
+    /// The volumes are read by the [`AudioMixer`] every frame.
+    pub fn apply(&self, world: &World) {
+        world.resource_mut::<Window>().fullscreen = self.fullscreen;
+        world.resource_mut::<Rumble>().enabled = self.rumble;
+    }
+}
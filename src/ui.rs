@@ -1,23 +1,35 @@
 use super::*;
 
+pub mod arena_select;
 pub mod countdown;
+pub mod dev_tools;
 pub mod fade;
 pub mod howtoplay;
 pub mod match_done;
 pub mod pause;
+pub mod reconnect;
 pub mod score_display;
+pub mod settings;
 pub mod splash;
 pub mod team_select;
+pub mod text_menu;
+pub mod touch_controls;
 pub mod winner;
 
+pub use arena_select::*;
 pub use countdown::*;
+pub use dev_tools::*;
 pub use fade::*;
 pub use howtoplay::*;
 pub use match_done::*;
 pub use pause::*;
+pub use reconnect::*;
 pub use score_display::*;
+pub use settings::*;
 pub use splash::*;
 pub use team_select::*;
+pub use text_menu::*;
+pub use touch_controls::*;
 pub use winner::*;
 
 pub struct UiSessionPlugin;
@@ -35,8 +47,12 @@ pub fn show_ui(world: &World) {
     fade::show(world);
     splash::show(world);
     team_select::show(world);
+    arena_select::show(world);
     pause::show(world);
+    settings::show(world);
+    reconnect::show(world);
     howtoplay::show(world);
+    touch_controls::show(world);
 
     if let Some(world) = world.resource_mut::<Sessions>().get_world(session::PLAY) {
         fade::show(world);
@@ -44,6 +60,7 @@ pub fn show_ui(world: &World) {
         score_display::show(world);
         match_done::show(world);
         winner::show(world);
+        dev_tools::show(world);
     }
 }
 
@@ -51,11 +68,14 @@ pub struct UiScalePlugin;
 impl SessionPlugin for UiScalePlugin {
     fn install(self, session: &mut SessionBuilder) {
         session.insert_resource(EguiSettings::default());
-        session.add_system_to_stage(Update, |world: &World, root: Root<Data>| {
-            let size = world.resource::<Window>().size;
-            world.resource_mut::<EguiSettings>().scale =
-                // TODO: Use resource instead of root asset & Move to utils module
-                (size.y / root.screen_size.y).min(size.x / root.screen_size.x) as f64;
-        });
+        session.add_system_to_stage(
+            Update,
+            |world: &World, root: Root<Data>, settings: Res<Settings>| {
+                let size = world.resource::<Window>().size;
+                world.resource_mut::<EguiSettings>().scale =
+                    // TODO: Use resource instead of root asset & Move to utils module
+                    settings.scale(size, root.screen_size) as f64;
+            },
+        );
     }
 }
@@ -0,0 +1,211 @@
+use super::*;
+
+#[derive(HasSchema, Clone, Default)]
+#[repr(C)]
+pub struct ArenaSelectAssets {
+    pub slots: ArenaSelectSlots,
+    /// How large the court preview is drawn relative to its full size.
+    pub preview_scale: f32,
+}
+
+#[derive(HasSchema, Clone, Copy, Default)]
+#[repr(C)]
+pub struct ArenaSelectSlots {
+    pub title: Vec2,
+    pub preview: Vec2,
+    pub name: Vec2,
+    pub rules: Vec2,
+    pub balls: Vec2,
+    pub left_arrow: Vec2,
+    pub right_arrow: Vec2,
+    /// The center of the back button for the mouse & touch.
+    pub back: Vec2,
+}
+
+/// The arena picker shown between team select and the match.
+#[derive(HasSchema, Clone, Default)]
+pub struct ArenaSelect {
+    pub visible: bool,
+    /// The index of the selected arena in [`Data::arenas`].
+    ///
+    /// This is kept between matches so the last arena stays selected.
+    pub index: usize,
+    /// Left & right pick the arena, its only item.
+    pub nav: MenuNav,
+}
+impl ArenaSelect {
+    /// Gives the picker to `owner` alone, or to everyone when unset.
+    pub fn open(&mut self, owner: Maybe<u32>) {
+        self.nav = MenuNav {
+            owner,
+            ..MenuNav::new(1)
+        };
+    }
+    pub fn cycle_left(&mut self, count: usize) {
+        self.index = (self.index + count.max(1) - 1) % count.max(1);
+    }
+    pub fn cycle_right(&mut self, count: usize) {
+        self.index = (self.index + 1) % count.max(1);
+    }
+    pub fn arena(&self, root: &Data) -> Handle<ArenaAsset> {
+        root.arenas
+            .get(self.index)
+            .or_else(|| root.arenas.first())
+            .copied()
+            .expect("at least one arena is required in `game.yaml`")
+    }
+}
+impl SessionPlugin for ArenaSelect {
+    fn install(self, session: &mut SessionBuilder) {
+        session.insert_resource(self);
+    }
+}
+
+fn foreground() -> egui::LayerId {
+    use egui::*;
+    LayerId::new(Order::Foreground, Id::new("arena_select_foreground"))
+}
+
+pub fn show(world: &World) {
+    let arena_select = world.resource::<ArenaSelect>();
+    if !arena_select.visible {
+        return;
+    }
+
+    let textures = world.resource::<EguiTextures>();
+    let ctx = world.resource::<EguiCtx>();
+    let asset_server = world.resource::<AssetServer>();
+    let root = asset_server.root::<Data>();
+    let locale = &asset_server.get(world.resource::<Settings>().locale(&root));
+    let arena = asset_server.get(arena_select.arena(&root));
+    let rules = world.resource::<MatchRules>();
+
+    let ArenaSelectAssets {
+        slots,
+        preview_scale,
+    } = root.menu.arena_select;
+    let HowToPlayAssets {
+        left_arrow,
+        right_arrow,
+        ..
+    } = root.menu.how_to_play;
+
+    let inner_font = asset_server
+        .get(root.font.primary_inner)
+        .family_name
+        .clone();
+    let outer_font = asset_server
+        .get(root.font.primary_outer)
+        .family_name
+        .clone();
+    let inner = TextPainter::standard()
+        .size(7.0)
+        .family(inner_font)
+        .color(Color32::WHITE)
+        .align2(Align2::CENTER_CENTER);
+    let outer = TextPainter::standard()
+        .size(7.0)
+        .family(outer_font)
+        .align2(Align2::CENTER_CENTER);
+
+    use egui::*;
+
+    let area = Area::new("arena_select")
+        .anchor(Align2::CENTER_CENTER, [0., 0.])
+        .show(&ctx, |ui| {
+            ui.image(load::SizedTexture::new(
+                textures.get(root.menu.splash.bg),
+                root.screen_size.to_array(),
+            ));
+        });
+    let origin = area.response.rect.min;
+    let mut painter = ctx.layer_painter(foreground());
+
+    painter.set_clip_rect(area.response.rect);
+
+    let (mut rules_rect, mut balls_rect, mut back) = (Rect::NOTHING, Rect::NOTHING, Rect::NOTHING);
+    for builder in [&inner, &outer] {
+        builder
+            .clone()
+            .text(locale.get("choose-arena"))
+            .pos(origin + slots.title.to_array().into())
+            .paint(&painter);
+        builder
+            .clone()
+            .text(locale.get(&arena.name))
+            .pos(origin + slots.name.to_array().into())
+            .paint(&painter);
+        back = builder
+            .clone()
+            .text(locale.get("back"))
+            .pos(origin + slots.back.to_array().into())
+            .paint(&painter);
+        rules_rect = builder
+            .clone()
+            .text(locale.get(if rules.power_ups {
+                "power-ups-on"
+            } else {
+                "power-ups-off"
+            }))
+            .pos(origin + slots.rules.to_array().into())
+            .paint(&painter);
+        balls_rect = builder
+            .clone()
+            .text(format!("{} {}", locale.get("ball-count"), rules.ball_count))
+            .pos(origin + slots.balls.to_array().into())
+            .paint(&painter);
+    }
+
+    let preview = arena
+        .court
+        .image_painter()
+        .size(arena.court.egui_size() * preview_scale)
+        .pos(origin + slots.preview.to_array().into())
+        .align2(Align2::CENTER_CENTER)
+        .paint(&painter, &textures);
+
+    let (mut left, mut right) = (Rect::NOTHING, Rect::NOTHING);
+    if root.arenas.len() > 1 {
+        left = left_arrow.paint_at(
+            origin + slots.left_arrow.to_array().into(),
+            &painter,
+            &textures,
+        );
+        right = right_arrow.paint_at(
+            origin + slots.right_arrow.to_array().into(),
+            &painter,
+            &textures,
+        );
+    }
+    let arena_count = root.arenas.len();
+    drop((arena_select, rules));
+
+    // The pointer does what the buttons would, but only once the fade in is over
+    // and only for whoever the picker is open to.
+    if *world.resource::<MenuState>() != MenuState::ArenaSelect
+        || !world.resource::<ArenaSelect>().nav.can_use_pointer()
+    {
+        return;
+    }
+    if hit_area(&ctx, "arena_select_left", left).clicked() {
+        world.resource_mut::<ArenaSelect>().cycle_left(arena_count);
+    }
+    if hit_area(&ctx, "arena_select_right", right).clicked() {
+        world.resource_mut::<ArenaSelect>().cycle_right(arena_count);
+    }
+    if hit_area(&ctx, "arena_select_rules", rules_rect.expand(2.0)).clicked() {
+        let mut rules = world.resource_mut::<MatchRules>();
+        rules.power_ups = !rules.power_ups;
+    }
+    if hit_area(&ctx, "arena_select_balls", balls_rect.expand(2.0)).clicked() {
+        let mut rules = world.resource_mut::<MatchRules>();
+        rules.ball_count = rules.ball_count % MatchRules::MAX_BALL_COUNT + 1;
+    }
+    let mut arena_select = world.resource_mut::<ArenaSelect>();
+    arena_select
+        .nav
+        .pointer(0, &hit_area(&ctx, "arena_select_preview", preview));
+    arena_select
+        .nav
+        .pointer_back(&hit_area(&ctx, "arena_select_back", back.expand(2.0)));
+}
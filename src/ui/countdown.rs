@@ -99,8 +99,8 @@ pub fn show(world: &World) {
             if progress != countdown.sound_marker {
                 countdown.sound_marker = progress;
                 world
-                    .resource_mut::<AudioCenter>()
-                    .play_sound(*countdown_first, countdown_first.volume());
+                    .resource_mut::<AudioMixer>()
+                    .play(AudioBus::Ui, countdown_first);
             }
             let asset = asset_server.get(numbers);
             let width = asset.tile_size.x;
@@ -130,8 +130,8 @@ pub fn show(world: &World) {
             if progress != countdown.sound_marker {
                 countdown.sound_marker = progress;
                 world
-                    .resource_mut::<AudioCenter>()
-                    .play_sound(*countdown_final, countdown_final.volume());
+                    .resource_mut::<AudioMixer>()
+                    .play(AudioBus::Ui, countdown_final);
             }
 
             painter.image(
@@ -0,0 +1,340 @@
+use super::*;
+
+/// A developer overlay for inspecting and tweaking a running match.
+///
+/// - `F1` toggles the overlay.
+///
+/// While it's shown:
+/// - `F5` pauses & resumes the simulation.
+/// - `F6` steps a single frame while paused.
+/// - `F7` & `F8` slow down & speed up the simulation.
+#[derive(HasSchema, Clone, Default)]
+pub struct DevTools {
+    pub visual: Visual,
+}
+impl SessionPlugin for DevTools {
+    fn install(self, session: &mut SessionBuilder) {
+        session.insert_resource(self);
+    }
+}
+
+/// Whether or not the key was pressed this frame.
+///
+/// This is checked while showing the ui because the play session's systems
+/// don't run while it is paused.
+pub fn key_just_pressed(world: &World, key: KeyCode) -> bool {
+    world
+        .resource::<KeyboardInputs>()
+        .key_events
+        .iter()
+        .any(|input| input.button_state == ButtonState::Pressed && input.key_code == Set(key))
+}
+
+pub fn show(world: &World) {
+    if key_just_pressed(world, KeyCode::F1) {
+        let mut dev_tools = world.resource_mut::<DevTools>();
+        if dev_tools.visual.shown() {
+            dev_tools.visual.hide();
+            // Hiding the overlay shouldn't leave the match frozen or running at another speed.
+            *world.resource_mut::<RunnerControl>() = default();
+        } else {
+            dev_tools.visual.show();
+        }
+    }
+    if !world.resource::<DevTools>().visual.shown() {
+        return;
+    }
+    {
+        let mut control = world.resource_mut::<RunnerControl>();
+        if key_just_pressed(world, KeyCode::F5) {
+            control.toggle_pause();
+        }
+        if key_just_pressed(world, KeyCode::F6) {
+            control.step();
+        }
+        if key_just_pressed(world, KeyCode::F7) {
+            control.slower();
+        }
+        if key_just_pressed(world, KeyCode::F8) {
+            control.faster();
+        }
+    }
+
+    use egui::*;
+    Window::new("dev-tools")
+        .title_bar(false)
+        .resizable(false)
+        .default_pos([2.0, 2.0])
+        .show(&world.resource::<EguiCtx>(), |ui| {
+            compact_style(ui);
+            runner_ui(world, ui);
+            ui.separator();
+            match_ui(world, ui);
+            ui.separator();
+            players_ui(world, ui);
+            ui.separator();
+            ball_ui(world, ui);
+            ui.separator();
+            constants_ui(world, ui);
+            ui.separator();
+            input_ui(world, ui);
+        });
+}
+
+/// The overlay is drawn in the scaled up ui space so everything is shrunk to fit.
+fn compact_style(ui: &mut egui::Ui) {
+    let style = ui.style_mut();
+    for font in style.text_styles.values_mut() {
+        font.size = 4.0;
+    }
+    style.spacing.item_spacing = egui::vec2(2.0, 1.0);
+    style.spacing.button_padding = egui::vec2(1.0, 0.0);
+    style.spacing.interact_size = egui::vec2(16.0, 5.0);
+    style.spacing.window_margin = egui::Margin::same(2.0);
+}
+
+fn runner_ui(world: &World, ui: &mut egui::Ui) {
+    let RunnerStatus {
+        frame,
+        steps,
+        alpha,
+    } = world.resource::<RunnerStatus>().clone();
+    let mut control = world.resource_mut::<RunnerControl>();
+
+    ui.label(format!("Steps: {steps} Alpha: {alpha:.2}"));
+    ui.horizontal(|ui| {
+        ui.label(format!("Frame: {frame}"));
+        let text = if control.paused { "Resume" } else { "Pause" };
+        if ui.button(text).clicked() {
+            control.toggle_pause();
+        }
+        if ui
+            .add_enabled(control.paused, egui::Button::new("Step"))
+            .clicked()
+        {
+            control.step();
+        }
+    });
+    ui.horizontal(|ui| {
+        ui.label("Speed:");
+        let mut time_scale = control.time_scale;
+        ui.add(
+            egui::Slider::new(
+                &mut time_scale,
+                RunnerControl::MIN_TIME_SCALE..=RunnerControl::MAX_TIME_SCALE,
+            )
+            .suffix("x"),
+        );
+        if ui.button("1x").clicked() {
+            time_scale = 1.0;
+        }
+        control.set_time_scale(time_scale);
+    });
+}
+
+fn match_ui(world: &World, ui: &mut egui::Ui) {
+    let play_state = *world.resource::<PlayState>();
+    let pin_score = *world.resource::<PinScore>();
+
+    ui.label(format!("Play State: {play_state:?}"));
+    ui.label(format!("Pin Score: {} - {}", pin_score.a, pin_score.b));
+}
+
+fn players_ui(world: &World, ui: &mut egui::Ui) {
+    let entities = world.resource::<Entities>();
+    let players = world.component::<Player>();
+    let states = world.component::<State>();
+
+    egui::Grid::new("dev-tools-players")
+        .striped(true)
+        .show(ui, |ui| {
+            ui.label("Player");
+            ui.label("State");
+            ui.label("Age");
+            ui.end_row();
+
+            for (_player_e, (player, state)) in entities.iter_with((&players, &states)) {
+                ui.label(format!("{:?}", player.id));
+                ui.label(state.current.as_str());
+                ui.label(state.age().to_string());
+                ui.end_row();
+            }
+        });
+}
+
+fn ball_ui(world: &World, ui: &mut egui::Ui) {
+    let entities = world.resource::<Entities>();
+    let mut balls = world.component_mut::<Ball>();
+    let mut transforms = world.component_mut::<Transform>();
+    let mut states = world.component_mut::<State>();
+
+    for (ball_e, ball) in entities.iter_with(&mut balls) {
+        let Some(transform) = transforms.get_mut(ball_e) else {
+            continue;
+        };
+        let Ball {
+            velocity,
+            dribble_pos,
+            owner,
+            ..
+        } = ball;
+
+        ui.label(format!("Ball Owner: {:?}", owner.option()));
+        ui.label(format!("Velocity: {:.2}, {:.2}", velocity.x, velocity.y));
+        ui.label(format!(
+            "Dribble Pos: {:.2}, {:.2}",
+            dribble_pos.x, dribble_pos.y
+        ));
+
+        ui.horizontal(|ui| {
+            ui.label("Position:");
+            let x = ui.add(egui::DragValue::new(&mut transform.translation.x));
+            let y = ui.add(egui::DragValue::new(&mut transform.translation.y));
+            let center = ui.button("Center").clicked();
+
+            if center {
+                transform.translation.x = 0.0;
+                transform.translation.y = 0.0;
+            }
+            // Teleporting takes the ball away from its owner.
+            if center || x.changed() || y.changed() {
+                if let Maybe::Set(owner_e) = *owner {
+                    if let Some(state) = states.get_mut(owner_e) {
+                        state.current = player::state::free();
+                    }
+                }
+                *owner = Maybe::Unset;
+                *velocity = default();
+            }
+        });
+    }
+}
+
+fn constants_ui(world: &World, ui: &mut egui::Ui) {
+    let mut constants = world.resource_mut::<Constants>();
+
+    egui::CollapsingHeader::new("Constants").show(ui, |ui| {
+        let Constants {
+            ball_bounds,
+            player_bounds,
+            pin_count,
+            pin_padding,
+            dribble_speed,
+            run_speed,
+            tackle_speed,
+            tackle_friction,
+            kick_power,
+            player_radius,
+            pass_lead,
+            kick_frames,
+            tackle_frames,
+            tackled_frames,
+            pass_frames,
+            recieve_frames,
+            turn_frames,
+            input_buffer_frames,
+            dribble_smoothing,
+            dribble_smoothing_threshold,
+            ball_radius,
+            ball_friction,
+            ball_etransfer,
+            ball_border_slide,
+            pin_radius,
+        } = &mut *constants;
+
+        egui::Grid::new("dev-tools-constants").show(ui, |ui| {
+            vec2_row(ui, "ball_bounds", ball_bounds);
+            vec2_row(ui, "player_bounds", player_bounds);
+            drag_row(ui, "pin_count", pin_count, 0.1);
+            vec2_row(ui, "pin_padding", pin_padding);
+
+            drag_row(ui, "dribble_speed", dribble_speed, 0.01);
+            drag_row(ui, "run_speed", run_speed, 0.01);
+            drag_row(ui, "tackle_speed", tackle_speed, 0.01);
+            drag_row(ui, "tackle_friction", tackle_friction, 0.001);
+            drag_row(ui, "kick_power", kick_power, 0.01);
+            drag_row(ui, "player_radius", player_radius, 0.1);
+            drag_row(ui, "pass_lead", pass_lead, 0.01);
+
+            drag_row(ui, "kick_frames", kick_frames, 0.1);
+            drag_row(ui, "tackle_frames", tackle_frames, 0.1);
+            drag_row(ui, "tackled_frames", tackled_frames, 0.1);
+            drag_row(ui, "pass_frames", pass_frames, 0.1);
+            drag_row(ui, "recieve_frames", recieve_frames, 0.1);
+            drag_row(ui, "turn_frames", turn_frames, 0.1);
+            drag_row(ui, "input_buffer_frames", input_buffer_frames, 0.1);
+            drag_row(ui, "dribble_smoothing", dribble_smoothing, 0.01);
+            drag_row(
+                ui,
+                "dribble_smoothing_threshold",
+                dribble_smoothing_threshold,
+                0.01,
+            );
+
+            drag_row(ui, "ball_radius", ball_radius, 0.1);
+            drag_row(ui, "ball_friction", ball_friction, 0.001);
+            drag_row(ui, "ball_etransfer", ball_etransfer, 0.01);
+            drag_row(ui, "ball_border_slide", ball_border_slide, 0.01);
+
+            drag_row(ui, "pin_radius", pin_radius, 0.1);
+        });
+
+        if ui.button("Reset").clicked() {
+            *constants = world.resource::<Arena>().constants(&world.asset_server());
+        }
+    });
+}
+
+fn input_ui(world: &World, ui: &mut egui::Ui) {
+    let mut local_inputs = world.resource_mut::<LocalInputs>();
+
+    egui::CollapsingHeader::new("Input").show(ui, |ui| {
+        for (gamepad, input) in &mut local_inputs.gamepads {
+            ui.label(format!(
+                "Gamepad {gamepad}: raw {:.2?} {:.2?}",
+                input.raw_left_stick, input.raw_right_stick
+            ));
+            let InputProfile {
+                radial_deadzone,
+                axial_deadzone,
+                response_curve,
+                stroke,
+                left_center,
+                right_center,
+                rumble,
+            } = &mut input.profile;
+
+            egui::Grid::new(("dev-tools-input", *gamepad)).show(ui, |ui| {
+                drag_row(ui, "radial_deadzone", radial_deadzone, 0.01);
+                drag_row(ui, "axial_deadzone", axial_deadzone, 0.01);
+                drag_row(ui, "response_curve", response_curve, 0.01);
+                drag_row(ui, "stroke", stroke, 0.01);
+                vec2_row(ui, "left_center", left_center);
+                vec2_row(ui, "right_center", right_center);
+                drag_row(ui, "rumble", rumble, 0.01);
+            });
+            if ui.button("Calibrate").clicked() {
+                input.calibrate();
+            }
+        }
+    });
+    // Saved once a value is let go of rather than on every frame it's dragged.
+    if !ui.ctx().input(|input| input.pointer.any_down()) {
+        local_inputs.save_profiles(&mut world.resource_mut::<Storage>());
+    }
+}
+
+fn drag_row<N: egui::emath::Numeric>(ui: &mut egui::Ui, label: &str, value: &mut N, speed: f64) {
+    ui.label(label);
+    ui.add(egui::DragValue::new(value).speed(speed));
+    ui.end_row();
+}
+
+fn vec2_row(ui: &mut egui::Ui, label: &str, value: &mut Vec2) {
+    ui.label(label);
+    ui.horizontal(|ui| {
+        ui.add(egui::DragValue::new(&mut value.x).speed(0.1));
+        ui.add(egui::DragValue::new(&mut value.y).speed(0.1));
+    });
+    ui.end_row();
+}
@@ -21,6 +21,8 @@ pub struct HowToPlaySlots {
     pub overview_tr: Vec2,
     pub overview_bl: Vec2,
     pub overview_br: Vec2,
+    /// The center of the back button for the mouse & touch.
+    pub back: Vec2,
 }
 
 #[derive(HasSchema, Clone, Default, PartialEq, Eq)]
@@ -50,7 +52,7 @@ pub fn show(world: &World) {
     let ctx = world.resource::<EguiCtx>();
     let asset_server = world.resource::<AssetServer>();
     let root = asset_server.root::<Data>();
-    let locale = &asset_server.get(root.localization);
+    let locale = &asset_server.get(world.resource::<Settings>().locale(&root));
 
     let HowToPlayAssets {
         slots,
@@ -123,13 +125,13 @@ pub fn show(world: &World) {
         .pos(origin + offset + slots.overview_tl.to_array().into())
         .paint(&painter);
 
-    let atlas = asset_server.get(root.sprite.b_pin);
+    let atlas = asset_server.get(root.sprite.team_color(1).pin);
     AtlasPainter::new(atlas.clone())
         .size((atlas.tile_size * 2.).to_array().into())
         .align2(Align2::RIGHT_CENTER)
         .pos(origin + offset + slots.overview_tr.to_array().into())
         .paint(&painter, &textures);
-    let atlas = asset_server.get(root.sprite.a_pin);
+    let atlas = asset_server.get(root.sprite.team_color(0).pin);
     AtlasPainter::new(atlas.clone())
         .size((atlas.tile_size * 2.).to_array().into())
         .align2(Align2::RIGHT_TOP)
@@ -147,7 +149,7 @@ pub fn show(world: &World) {
         .pos(origin + offset + slots.overview_tr.to_array().into())
         .paint(&painter);
 
-    let atlas = asset_server.get(root.sprite.b_pin);
+    let atlas = asset_server.get(root.sprite.team_color(1).pin);
     AtlasPainter::new(atlas.clone())
         .size((atlas.tile_size * 2.).to_array().into())
         .index(2)
@@ -197,34 +199,74 @@ pub fn show(world: &World) {
         &textures,
     );
 
-    // Arrows
-    match *howtoplay {
-        HowToPlay::GameOverview => {
-            right_arrow.paint_at(
-                origin + slots.right_arrow.to_array().into(),
-                &painter,
-                &textures,
-            );
-        }
-        HowToPlay::SingleStickControls => {
-            left_arrow.paint_at(
-                origin + slots.left_arrow.to_array().into(),
-                &painter,
-                &textures,
-            );
-            right_arrow.paint_at(
-                origin + slots.right_arrow.to_array().into(),
-                &painter,
-                &textures,
-            );
-        }
-        HowToPlay::DualStickControls => {
-            left_arrow.paint_at(
-                origin + slots.left_arrow.to_array().into(),
-                &painter,
-                &textures,
+    let mut back = Rect::NOTHING;
+    for builder in [&inner, &outer] {
+        back = builder
+            .clone()
+            .text(locale.get("back"))
+            .align2(Align2::CENTER_CENTER)
+            .pos(origin + slots.back.to_array().into())
+            .paint(&painter);
+    }
+
+    // Arrows, with the page each one turns to.
+    let (previous, next) = match *howtoplay {
+        HowToPlay::GameOverview => (None, Some(HowToPlay::SingleStickControls)),
+        HowToPlay::SingleStickControls => (
+            Some(HowToPlay::GameOverview),
+            Some(HowToPlay::DualStickControls),
+        ),
+        HowToPlay::DualStickControls => (Some(HowToPlay::SingleStickControls), None),
+        HowToPlay::Hidden => unreachable!(),
+    };
+    let mut left = Rect::NOTHING;
+    let mut right = Rect::NOTHING;
+    if previous.is_some() {
+        left = left_arrow.paint_at(
+            origin + slots.left_arrow.to_array().into(),
+            &painter,
+            &textures,
+        );
+    }
+    if next.is_some() {
+        right = right_arrow.paint_at(
+            origin + slots.right_arrow.to_array().into(),
+            &painter,
+            &textures,
+        );
+    }
+    drop(howtoplay);
+
+    let state = *world.resource::<MenuState>();
+    if state == MenuState::FadeTransition {
+        return;
+    }
+    // Over the pause menu it's only for whoever paused, like the pause menu itself.
+    if state != MenuState::HowToPlay && !world.resource::<Pause>().nav.can_use_pointer() {
+        return;
+    }
+    let page = [
+        (previous, hit_area(&ctx, "how_to_play_left", left)),
+        (next, hit_area(&ctx, "how_to_play_right", right)),
+    ]
+    .into_iter()
+    .find_map(|(page, response)| page.filter(|_| response.clicked()));
+    if let Some(page) = page {
+        *world.resource_mut::<HowToPlay>() = page;
+    }
+    if hit_area(&ctx, "how_to_play_back", back.expand(2.0)).clicked() {
+        if state == MenuState::HowToPlay {
+            start_fade(
+                world,
+                FadeTransition {
+                    hide: how_to_play_hide,
+                    prep: splash_prep,
+                    finish: splash_finish,
+                },
             );
+        } else {
+            // Opened over the pause menu.
+            *world.resource_mut::<HowToPlay>() = HowToPlay::Hidden;
         }
-        HowToPlay::Hidden => unreachable!(),
     }
 }
@@ -1,43 +1,32 @@
 use super::*;
 
-#[derive(HasSchema, Clone, Default)]
-#[repr(C)]
-pub struct MatchDoneAssets {
-    pub menu: SizedImageAsset,
-    pub cursor: SizedImageAsset,
-    pub play_again_pos: Vec2,
-    pub team_select_pos: Vec2,
-    pub quit_pos: Vec2,
-}
-
-#[derive(HasSchema, Clone, Default, Copy, Deref, DerefMut)]
+#[derive(HasSchema, Clone, Copy)]
 pub struct MatchDone {
     pub visual: Visual,
-    #[deref]
-    pub state: MatchDoneState,
+    pub nav: MenuNav,
+}
+impl Default for MatchDone {
+    fn default() -> Self {
+        Self {
+            visual: default(),
+            nav: MenuNav::new(MatchDoneState::ENTRIES.len()),
+        }
+    }
 }
-#[derive(HasSchema, Clone, Default, Copy)]
+#[derive(HasSchema, Clone, Default, Copy, PartialEq, Eq)]
 pub enum MatchDoneState {
     #[default]
     PlayAgain,
     TeamSelect,
     Quit,
 }
-impl MatchDone {
-    pub fn cycle_up(&mut self) {
-        self.state = match self.state {
-            MatchDoneState::PlayAgain => MatchDoneState::Quit,
-            MatchDoneState::TeamSelect => MatchDoneState::PlayAgain,
-            MatchDoneState::Quit => MatchDoneState::TeamSelect,
-        }
-    }
-    pub fn cycle_down(&mut self) {
-        self.state = match self.state {
-            MatchDoneState::PlayAgain => MatchDoneState::TeamSelect,
-            MatchDoneState::TeamSelect => MatchDoneState::Quit,
-            MatchDoneState::Quit => MatchDoneState::PlayAgain,
-        }
-    }
+impl MatchDoneState {
+    /// The order the entries are listed in, with the localization key of each.
+    pub const ENTRIES: [(MatchDoneState, &'static str); 3] = [
+        (MatchDoneState::PlayAgain, "play-again"),
+        (MatchDoneState::TeamSelect, "select-teams"),
+        (MatchDoneState::Quit, "quit"),
+    ];
 }
 
 impl SessionPlugin for MatchDone {
@@ -46,48 +35,20 @@ impl SessionPlugin for MatchDone {
     }
 }
 pub fn show(world: &World) {
-    let match_done = world.resource::<MatchDone>();
-
-    if !match_done.visual.shown() {
+    if !world.resource::<MatchDone>().visual.shown() {
         return;
     }
     let asset_server = world.resource::<AssetServer>();
     let root = asset_server.root::<Data>();
-    let MatchDoneAssets {
-        menu,
-        cursor,
-        play_again_pos,
-        team_select_pos,
-        quit_pos,
-    } = root.menu.match_done;
-
-    use egui::*;
-    Area::new("match-done-ui")
-        .anchor(Align2::CENTER_CENTER, [0., 0.])
-        .order(Order::Foreground)
-        .show(&world.resource::<EguiCtx>(), |ui| {
-            let textures = world.resource::<EguiTextures>();
-            ui.horizontal(|ui| {
-                ui.style_mut().spacing.item_spacing = Vec2::ZERO;
-                let response = ui.image(ImageSource::Texture(load::SizedTexture::new(
-                    textures.get(*menu),
-                    menu.egui_size(),
-                )));
+    let locale = &asset_server.get(world.resource::<Settings>().locale(&root));
 
-                let pos = match match_done.state {
-                    MatchDoneState::PlayAgain => play_again_pos,
-                    MatchDoneState::TeamSelect => team_select_pos,
-                    MatchDoneState::Quit => quit_pos,
-                };
-                ui.painter().image(
-                    textures.get(*cursor),
-                    Rect::from_min_size(
-                        response.rect.min + egui::Vec2::new(pos.x, pos.y),
-                        cursor.egui_size(),
-                    ),
-                    default_uv(),
-                    Color32::WHITE,
-                )
-            });
-        });
+    let options = MatchDoneState::ENTRIES.map(|(_, key)| locale.get(key));
+    show_text_menu(
+        world,
+        "match-done-ui",
+        &root.menu.match_done,
+        None,
+        &options.iter().map(|text| text.as_ref()).collect::<Vec<_>>(),
+        &mut world.resource_mut::<MatchDone>().nav,
+    );
 }
@@ -1,31 +1,35 @@
 use super::*;
 
-#[derive(HasSchema, Clone, Default)]
-#[repr(C)]
-pub struct PauseAssets {
-    pub menu: SizedImageAsset,
-    pub cursor: SizedImageAsset,
-    pub continue_pos: Vec2,
-    pub restart_pos: Vec2,
-    pub team_select_pos: Vec2,
+/// The pause menu, [`MenuNav::owner`] is the controller that opened it.
+#[derive(HasSchema, Clone, Copy)]
+pub struct Pause {
+    pub visible: bool,
+    pub nav: MenuNav,
 }
-
-#[derive(HasSchema, Clone, Default, Copy, PartialEq, Eq)]
-pub enum Pause {
-    #[default]
-    Hidden,
-    Continue,
-    Restart,
-    Quit,
+impl Default for Pause {
+    fn default() -> Self {
+        Self {
+            visible: false,
+            nav: MenuNav::new(PauseEntry::ALL.len()),
+        }
+    }
 }
 impl Pause {
-    pub fn cycle(&mut self) {
-        match self {
-            Pause::Hidden => {}
-            Pause::Continue => *self = Pause::Restart,
-            Pause::Restart => *self = Pause::Quit,
-            Pause::Quit => *self = Pause::Continue,
-        }
+    /// Opens the menu on its first entry for `gamepad` alone.
+    pub fn open(&mut self, gamepad: u32) {
+        *self = Self {
+            visible: true,
+            nav: MenuNav {
+                owner: Maybe::Set(gamepad),
+                ..Self::default().nav
+            },
+        };
+    }
+    pub fn close(&mut self) {
+        *self = Self::default();
+    }
+    pub fn entry(&self) -> PauseEntry {
+        PauseEntry::ALL[self.nav.focus].0
     }
 }
 impl SessionPlugin for Pause {
@@ -33,50 +37,49 @@ impl SessionPlugin for Pause {
         session.insert_resource(self);
     }
 }
-pub fn show(world: &World) {
-    let pause = world.resource::<Pause>();
 
-    if let Pause::Hidden = *pause {
+#[derive(HasSchema, Clone, Default, Copy, PartialEq, Eq)]
+pub enum PauseEntry {
+    #[default]
+    Continue,
+    Restart,
+    Controls,
+    Settings,
+    Forfeit,
+    Quit,
+}
+impl PauseEntry {
+    /// The order the entries are listed in, with the localization key of each.
+    pub const ALL: [(PauseEntry, &'static str); 6] = [
+        (PauseEntry::Continue, "continue"),
+        (PauseEntry::Restart, "restart"),
+        (PauseEntry::Controls, "controls"),
+        (PauseEntry::Settings, "settings"),
+        (PauseEntry::Forfeit, "forfeit"),
+        (PauseEntry::Quit, "quit"),
+    ];
+}
+
+pub fn show(world: &World) {
+    if !world.resource::<Pause>().visible {
+        return;
+    }
+    if world.resource::<SettingsMenu>().visible
+        || *world.resource::<HowToPlay>() != HowToPlay::Hidden
+    {
         return;
     }
     let asset_server = world.resource::<AssetServer>();
     let root = asset_server.root::<Data>();
-    let PauseAssets {
-        menu,
-        cursor,
-        continue_pos,
-        restart_pos,
-        team_select_pos,
-    } = root.menu.pause;
-
-    use egui::*;
-    Area::new("pause-ui")
-        .anchor(Align2::CENTER_CENTER, [0., 0.])
-        .order(Order::Foreground)
-        .show(&world.resource::<EguiCtx>(), |ui| {
-            let textures = world.resource::<EguiTextures>();
-            ui.horizontal(|ui| {
-                ui.style_mut().spacing.item_spacing = Vec2::ZERO;
-                let response = ui.image(ImageSource::Texture(load::SizedTexture::new(
-                    textures.get(*menu),
-                    menu.egui_size(),
-                )));
+    let locale = &asset_server.get(world.resource::<Settings>().locale(&root));
 
-                let pos = match *pause {
-                    Pause::Continue => continue_pos,
-                    Pause::Restart => restart_pos,
-                    Pause::Quit => team_select_pos,
-                    Pause::Hidden => unreachable!(),
-                };
-                ui.painter().image(
-                    textures.get(*cursor),
-                    Rect::from_min_size(
-                        response.rect.min + egui::Vec2::new(pos.x, pos.y),
-                        cursor.egui_size(),
-                    ),
-                    default_uv(),
-                    Color32::WHITE,
-                )
-            });
-        });
+    let options = PauseEntry::ALL.map(|(_, key)| locale.get(key));
+    show_text_menu(
+        world,
+        "pause-ui",
+        &root.menu.pause,
+        Some(&locale.get("pause")),
+        &options.iter().map(|text| text.as_ref()).collect::<Vec<_>>(),
+        &mut world.resource_mut::<Pause>().nav,
+    );
 }
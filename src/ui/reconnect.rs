@@ -0,0 +1,74 @@
+use super::*;
+
+/// The prompt shown while a controller used in the match is unplugged.
+#[derive(HasSchema, Clone, Default)]
+pub struct Reconnect {
+    /// The player whose controller is missing.
+    pub player: Maybe<PlayerInfo>,
+}
+impl SessionPlugin for Reconnect {
+    fn install(self, session: &mut SessionBuilder) {
+        session.insert_resource(self);
+    }
+}
+
+pub fn show(world: &World) {
+    let reconnect = world.resource::<Reconnect>();
+    let Maybe::Set(player) = reconnect.player else {
+        return;
+    };
+
+    let ctx = world.resource::<EguiCtx>();
+    let asset_server = world.resource::<AssetServer>();
+    let root = asset_server.root::<Data>();
+    let locale = &asset_server.get(world.resource::<Settings>().locale(&root));
+
+    let inner_font = asset_server
+        .get(root.font.primary_inner)
+        .family_name
+        .clone();
+    let outer_font = asset_server
+        .get(root.font.primary_outer)
+        .family_name
+        .clone();
+    let inner = TextPainter::standard()
+        .size(7.0)
+        .family(inner_font)
+        .color(Color32::WHITE)
+        .align2(Align2::CENTER_CENTER);
+    let outer = TextPainter::standard()
+        .size(7.0)
+        .family(outer_font)
+        .align2(Align2::CENTER_CENTER);
+
+    use egui::*;
+
+    let area = Area::new("reconnect")
+        .anchor(Align2::CENTER_CENTER, [0., 0.])
+        .order(Order::Foreground)
+        .show(&ctx, |ui| {
+            let size = root.screen_size.to_array().into();
+            let (rect, _) = ui.allocate_exact_size(size, Sense::hover());
+            ui.painter()
+                .rect_filled(rect, 0.0, Color32::BLACK.gamma_multiply(0.7));
+        });
+    let center = area.response.rect.center();
+    let painter = ctx.layer_painter(LayerId::new(Order::Tooltip, Id::new("reconnect_text")));
+
+    for builder in [&inner, &outer] {
+        builder
+            .clone()
+            .text(format!(
+                "{} P{}",
+                locale.get("reconnect-controller"),
+                player.number + 1
+            ))
+            .pos(center - vec2(0., 8.))
+            .paint(&painter);
+        builder
+            .clone()
+            .text(locale.get("reconnect-other"))
+            .pos(center + vec2(0., 8.))
+            .paint(&painter);
+    }
+}
@@ -0,0 +1,336 @@
+use super::*;
+
+#[derive(HasSchema, Clone, Copy, Default)]
+#[repr(C)]
+pub struct SettingsMenuAssets {
+    pub slots: SettingsMenuSlots,
+    /// The space between the rows of settings.
+    pub row_height: f32,
+}
+
+#[derive(HasSchema, Clone, Copy, Default)]
+#[repr(C)]
+pub struct SettingsMenuSlots {
+    pub title: Vec2,
+    /// Where the first row's name starts, the rows below it are [`SettingsMenuAssets::row_height`] apart.
+    pub label: Vec2,
+    /// Where the values of the settings end on each row.
+    pub value: f32,
+    /// Where the cursor is drawn relative to the selected row's label.
+    pub cursor: Vec2,
+    /// The center of the back button for the mouse & touch.
+    pub back: Vec2,
+}
+
+#[derive(HasSchema, Clone, Copy, Default, Debug, PartialEq, Eq)]
+pub enum SettingsRow {
+    #[default]
+    MasterVolume,
+    MusicVolume,
+    EffectsVolume,
+    Fullscreen,
+    WindowScale,
+    ScreenShake,
+    Rumble,
+    PowerUps,
+    BallCount,
+    Language,
+    StickDeadzone,
+    Calibrate,
+}
+impl SettingsRow {
+    pub const ALL: [SettingsRow; 12] = [
+        SettingsRow::MasterVolume,
+        SettingsRow::MusicVolume,
+        SettingsRow::EffectsVolume,
+        SettingsRow::Fullscreen,
+        SettingsRow::WindowScale,
+        SettingsRow::ScreenShake,
+        SettingsRow::Rumble,
+        SettingsRow::PowerUps,
+        SettingsRow::BallCount,
+        SettingsRow::Language,
+        SettingsRow::StickDeadzone,
+        SettingsRow::Calibrate,
+    ];
+    /// The largest [`InputProfile::radial_deadzone`] the menu goes up to.
+    pub const MAX_DEADZONE: f32 = 0.5;
+    /// How much the deadzone changes with each step.
+    pub const DEADZONE_STEP: f32 = 0.05;
+
+    pub fn label(self) -> &'static str {
+        match self {
+            SettingsRow::MasterVolume => "master-volume",
+            SettingsRow::MusicVolume => "music-volume",
+            SettingsRow::EffectsVolume => "effects-volume",
+            SettingsRow::Fullscreen => "fullscreen",
+            SettingsRow::WindowScale => "window-scale",
+            SettingsRow::ScreenShake => "screen-shake",
+            SettingsRow::Rumble => "rumble",
+            SettingsRow::PowerUps => "default-power-ups",
+            SettingsRow::BallCount => "default-ball-count",
+            SettingsRow::Language => "language",
+            SettingsRow::StickDeadzone => "stick-deadzone",
+            SettingsRow::Calibrate => "calibrate-sticks",
+        }
+    }
+    /// Whether the row changes the [`InputProfile`] of the controller using the menu
+    /// instead of the [`Settings`].
+    pub fn is_input(self) -> bool {
+        matches!(self, SettingsRow::StickDeadzone | SettingsRow::Calibrate)
+    }
+    /// Changes the controller's profile by `step` for the rows where [`Self::is_input`].
+    pub fn adjust_input(self, input: &mut LocalInput, step: i32) {
+        match self {
+            SettingsRow::StickDeadzone => {
+                let steps = (input.profile.radial_deadzone / Self::DEADZONE_STEP).round() as i32;
+                input.profile.radial_deadzone =
+                    ((steps + step) as f32 * Self::DEADZONE_STEP).clamp(0.0, Self::MAX_DEADZONE);
+            }
+            SettingsRow::Calibrate => input.calibrate(),
+            _ => {}
+        }
+    }
+    /// Changes the setting by `step`, toggles ignore the direction.
+    pub fn adjust(self, settings: &mut Settings, step: i32, root: &Data) {
+        match self {
+            SettingsRow::MasterVolume => {
+                settings.master_volume = Settings::step_volume(settings.master_volume, step)
+            }
+            SettingsRow::MusicVolume => {
+                settings.music_volume = Settings::step_volume(settings.music_volume, step)
+            }
+            SettingsRow::EffectsVolume => {
+                settings.effects_volume = Settings::step_volume(settings.effects_volume, step)
+            }
+            SettingsRow::Fullscreen => settings.fullscreen = !settings.fullscreen,
+            SettingsRow::WindowScale => {
+                settings.window_scale = (settings.window_scale as i32 + step)
+                    .rem_euclid(Settings::MAX_WINDOW_SCALE as i32 + 1)
+                    as u32
+            }
+            SettingsRow::ScreenShake => settings.screen_shake = !settings.screen_shake,
+            SettingsRow::Rumble => settings.rumble = !settings.rumble,
+            SettingsRow::PowerUps => {
+                let mut rules = settings.rules(root);
+                rules.power_ups = !rules.power_ups;
+                settings.rules = Maybe::Set(rules);
+            }
+            SettingsRow::BallCount => {
+                let mut rules = settings.rules(root);
+                rules.ball_count = (rules.ball_count as i32 + step)
+                    .clamp(1, MatchRules::MAX_BALL_COUNT as i32)
+                    as usize;
+                settings.rules = Maybe::Set(rules);
+            }
+            SettingsRow::Language => {
+                let count = root.languages.len() as i32;
+                if count == 0 {
+                    return;
+                }
+                let current = root
+                    .languages
+                    .iter()
+                    .position(|meta| meta.code == settings.language)
+                    .unwrap_or(0) as i32;
+                settings.language = root.languages[(current + step).rem_euclid(count) as usize]
+                    .code
+                    .clone();
+            }
+            SettingsRow::StickDeadzone | SettingsRow::Calibrate => {}
+        }
+    }
+    /// The setting's value as shown, the input rows show `profile`.
+    pub fn value(
+        self,
+        settings: &Settings,
+        profile: &InputProfile,
+        root: &Data,
+        locale: &LocalizationAsset,
+    ) -> String {
+        let toggle = |on: bool| locale.get(if on { "setting-on" } else { "setting-off" });
+        let volume = |volume: f64| format!("{}%", (volume * 100.0).round());
+
+        match self {
+            SettingsRow::MasterVolume => volume(settings.master_volume),
+            SettingsRow::MusicVolume => volume(settings.music_volume),
+            SettingsRow::EffectsVolume => volume(settings.effects_volume),
+            SettingsRow::Fullscreen => toggle(settings.fullscreen).to_string(),
+            SettingsRow::WindowScale => match settings.window_scale {
+                0 => locale.get("window-scale-fit").to_string(),
+                scale => format!("{scale}x"),
+            },
+            SettingsRow::ScreenShake => toggle(settings.screen_shake).to_string(),
+            SettingsRow::Rumble => toggle(settings.rumble).to_string(),
+            SettingsRow::PowerUps => toggle(settings.rules(root).power_ups).to_string(),
+            SettingsRow::BallCount => settings.rules(root).ball_count.to_string(),
+            SettingsRow::Language => root
+                .languages
+                .iter()
+                .find(|meta| meta.code == settings.language)
+                .or(root.languages.first())
+                .map_or(String::new(), |meta| meta.name.clone()),
+            SettingsRow::StickDeadzone => volume(profile.radial_deadzone as f64),
+            SettingsRow::Calibrate => {
+                let calibrated =
+                    profile.left_center != Vec2::ZERO || profile.right_center != Vec2::ZERO;
+                locale
+                    .get(if calibrated {
+                        "sticks-calibrated"
+                    } else {
+                        "sticks-default"
+                    })
+                    .to_string()
+            }
+        }
+    }
+}
+
+/// The settings screen, opened from the splash or the pause menu.
+#[derive(HasSchema, Clone)]
+pub struct SettingsMenu {
+    pub visible: bool,
+    pub nav: MenuNav,
+    /// Drawn over the paused match instead of the menu background,
+    /// closing it goes back to the pause menu.
+    pub from_pause: bool,
+}
+impl Default for SettingsMenu {
+    fn default() -> Self {
+        Self {
+            visible: false,
+            nav: MenuNav::new(SettingsRow::ALL.len()),
+            from_pause: false,
+        }
+    }
+}
+impl SettingsMenu {
+    pub fn row(&self) -> SettingsRow {
+        SettingsRow::ALL[self.nav.focus]
+    }
+}
+impl SessionPlugin for SettingsMenu {
+    fn install(self, session: &mut SessionBuilder) {
+        session.insert_resource(self);
+    }
+}
+
+fn foreground() -> egui::LayerId {
+    use egui::*;
+    LayerId::new(Order::Tooltip, Id::new("settings_foreground"))
+}
+
+pub fn show(world: &World) {
+    let menu = world.resource::<SettingsMenu>();
+    if !menu.visible {
+        return;
+    }
+
+    let textures = world.resource::<EguiTextures>();
+    let ctx = world.resource::<EguiCtx>();
+    let asset_server = world.resource::<AssetServer>();
+    let root = asset_server.root::<Data>();
+    let settings = world.resource::<Settings>();
+    let locale = &asset_server.get(settings.locale(&root));
+    let profile = menu
+        .nav
+        .user
+        .option()
+        .map(|gamepad| world.resource::<LocalInputs>().profile(gamepad))
+        .unwrap_or_default();
+
+    let SettingsMenuAssets { slots, row_height } = root.menu.settings;
+
+    let inner_font = asset_server
+        .get(root.font.primary_inner)
+        .family_name
+        .clone();
+    let outer_font = asset_server
+        .get(root.font.primary_outer)
+        .family_name
+        .clone();
+    let inner = TextPainter::standard()
+        .size(7.0)
+        .family(inner_font)
+        .color(Color32::WHITE);
+    let outer = TextPainter::standard().size(7.0).family(outer_font);
+
+    use egui::*;
+
+    let area = Area::new("settings")
+        .anchor(Align2::CENTER_CENTER, [0., 0.])
+        .order(Order::Foreground)
+        .show(&ctx, |ui| {
+            let size = root.screen_size.to_array();
+            if menu.from_pause {
+                let (rect, _) = ui.allocate_exact_size(size.into(), Sense::hover());
+                ui.painter()
+                    .rect_filled(rect, 0.0, Color32::BLACK.gamma_multiply(0.7));
+            } else {
+                ui.image(load::SizedTexture::new(
+                    textures.get(root.menu.splash.bg),
+                    size,
+                ));
+            }
+        });
+    let origin = area.response.rect.min;
+    let mut painter = ctx.layer_painter(foreground());
+
+    painter.set_clip_rect(area.response.rect);
+
+    let mut back = Rect::NOTHING;
+    for builder in [&inner, &outer] {
+        builder
+            .clone()
+            .text(locale.get("settings"))
+            .align2(Align2::CENTER_CENTER)
+            .pos(origin + slots.title.to_array().into())
+            .paint(&painter);
+        back = builder
+            .clone()
+            .text(locale.get("back"))
+            .align2(Align2::CENTER_CENTER)
+            .pos(origin + slots.back.to_array().into())
+            .paint(&painter);
+
+        for (i, row) in SettingsRow::ALL.into_iter().enumerate() {
+            let label = origin + slots.label.to_array().into() + vec2(0., row_height * i as f32);
+            builder
+                .clone()
+                .text(locale.get(row.label()))
+                .align2(Align2::LEFT_CENTER)
+                .pos(label)
+                .paint(&painter);
+            builder
+                .clone()
+                .text(row.value(&settings, &profile, &root, locale))
+                .align2(Align2::RIGHT_CENTER)
+                .pos(pos2(origin.x + slots.value, label.y))
+                .paint(&painter);
+        }
+    }
+
+    let cursor = root.menu.pause.cursor;
+    cursor.paint_at(
+        origin
+            + slots.label.to_array().into()
+            + slots.cursor.to_array().into()
+            + vec2(0., row_height * menu.nav.focus as f32),
+        &painter,
+        &textures,
+    );
+    drop(menu);
+
+    let mut menu = world.resource_mut::<SettingsMenu>();
+    for i in 0..SettingsRow::ALL.len() {
+        let label = origin + slots.label.to_array().into() + vec2(0., row_height * i as f32);
+        let row = Rect::from_min_max(
+            pos2(label.x, label.y - row_height / 2.),
+            pos2(origin.x + slots.value, label.y + row_height / 2.),
+        );
+        menu.nav
+            .pointer_adjust(i, &hit_area(&ctx, ("settings_row", i), row));
+    }
+    menu.nav
+        .pointer_back(&hit_area(&ctx, "settings_back", back.expand(2.0)));
+}
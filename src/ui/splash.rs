@@ -7,10 +7,6 @@ pub struct SplashAssets {
     pub bg: Handle<Image>,
     pub title: SizedImageAsset,
     pub button_bg: SizedImageAsset,
-    pub offline: SizedImageAsset,
-    pub offline_blink: SizedImageAsset,
-    pub how_to_play: SizedImageAsset,
-    pub how_to_play_blink: SizedImageAsset,
 }
 
 #[derive(HasSchema, Clone, Copy, Default)]
@@ -18,33 +14,22 @@ pub struct SplashAssets {
 pub struct SplashSlots {
     pub title: Vec2,
     pub selection: Vec2,
+    /// The centers of the entries.
     pub offline: Vec2,
     pub how_to_play: Vec2,
+    pub settings: Vec2,
 }
 
-#[derive(HasSchema, Clone, Default, PartialEq, Eq)]
-pub enum Splash {
-    #[default]
-    Hidden,
-    Offline,
-    // Online,
-    HowToPlay,
+#[derive(HasSchema, Clone, Copy)]
+pub struct Splash {
+    pub visible: bool,
+    pub nav: MenuNav,
 }
-impl Splash {
-    pub fn cycle_up(&mut self) {
-        match self {
-            Splash::Offline => *self = /* Self::Online */Self::HowToPlay,
-            // Splash::Online => *self = Self::Offline,
-            Splash::HowToPlay => *self = Self::Offline,
-            Splash::Hidden => {}
-        }
-    }
-    pub fn cycle_down(&mut self) {
-        match self {
-            Splash::Offline => *self = /* Self::Online */Self::HowToPlay,
-            // Splash::Online => *self = Self::HowToPlay,
-            Splash::HowToPlay => *self = Self::Offline,
-            Splash::Hidden => {}
+impl Default for Splash {
+    fn default() -> Self {
+        Self {
+            visible: false,
+            nav: MenuNav::new(SplashEntry::ALL.len()),
         }
     }
 }
@@ -53,18 +38,44 @@ impl SessionPlugin for Splash {
         session.insert_resource(self);
     }
 }
+
+#[derive(HasSchema, Clone, Copy, Default, PartialEq, Eq)]
+pub enum SplashEntry {
+    #[default]
+    Offline,
+    // Online,
+    HowToPlay,
+    Settings,
+}
+impl SplashEntry {
+    /// The order the entries are listed in, with the localization key of each.
+    pub const ALL: [(SplashEntry, &'static str); 3] = [
+        (SplashEntry::Offline, "offline"),
+        // (SplashEntry::Online, "online"),
+        (SplashEntry::HowToPlay, "how-to-play"),
+        (SplashEntry::Settings, "settings"),
+    ];
+    pub fn slot(self, slots: &SplashSlots) -> Vec2 {
+        match self {
+            SplashEntry::Offline => slots.offline,
+            SplashEntry::HowToPlay => slots.how_to_play,
+            SplashEntry::Settings => slots.settings,
+        }
+    }
+}
 fn foreground() -> egui::LayerId {
     use egui::*;
     LayerId::new(Order::Foreground, Id::new("splash_foreground"))
 }
 pub fn show(world: &World) {
-    let splash = world.resource::<Splash>();
-    if Splash::Hidden == *splash {
+    let Splash { visible, nav } = *world.resource::<Splash>();
+    if !visible {
         return;
     }
 
     let asset_server = world.resource::<AssetServer>();
     let root = asset_server.root::<Data>();
+    let locale = &asset_server.get(world.resource::<Settings>().locale(&root));
     let textures = world.resource::<EguiTextures>();
     let ctx = world.resource::<EguiCtx>();
 
@@ -73,10 +84,6 @@ pub fn show(world: &World) {
         bg,
         title,
         button_bg,
-        offline,
-        offline_blink,
-        how_to_play,
-        how_to_play_blink,
         ..
     } = root.menu.splash;
 
@@ -110,27 +117,36 @@ pub fn show(world: &World) {
         .offset(slots.selection.to_array().into())
         .paint(&painter, &textures);
 
-    let image = if splash == Splash::Offline {
-        offline_blink
-    } else {
-        offline
-    };
-    builder
-        .clone()
-        .image(*image)
-        .size(image.egui_size())
-        .offset(slots.offline.to_array().into())
-        .paint(&painter, &textures);
+    // The entries are text that's brighter while selected.
+    let inner_font = asset_server
+        .get(root.font.primary_inner)
+        .family_name
+        .clone();
+    let outer_font = asset_server
+        .get(root.font.primary_outer)
+        .family_name
+        .clone();
 
-    let image = if splash == Splash::HowToPlay {
-        how_to_play_blink
-    } else {
-        how_to_play
-    };
-    builder
-        .clone()
-        .image(*image)
-        .size(image.egui_size())
-        .offset(slots.how_to_play.to_array().into())
-        .paint(&painter, &textures);
+    for (index, (entry, key)) in SplashEntry::ALL.into_iter().enumerate() {
+        let inner = TextPainter::standard()
+            .size(7.0)
+            .family(inner_font.clone())
+            .color(if nav.focus == index {
+                Color32::WHITE
+            } else {
+                Color32::GRAY
+            });
+        let outer = TextPainter::standard().size(7.0).family(outer_font.clone());
+
+        let mut rect = Rect::NOTHING;
+        for builder in [inner, outer] {
+            rect = builder
+                .text(locale.get(key))
+                .align2(Align2::CENTER_CENTER)
+                .pos(area.response.rect.left_top() + entry.slot(&slots).to_array().into())
+                .paint(&painter);
+        }
+        let response = hit_area(&ctx, ("splash_entry", index), rect.expand(2.0));
+        world.resource_mut::<Splash>().nav.pointer(index, &response);
+    }
 }
@@ -12,6 +12,18 @@ impl SessionPlugin for TeamSelect {
     }
 }
 
+/// What the mouse or a touch did on the screen, applied after it's drawn.
+enum PointerAction {
+    Ready(u32),
+    Reverse(u32),
+    CycleOutfit(u32),
+    CycleColor(Team),
+    /// A controller was dragged and let go over a team, or over the center column.
+    Drop(u32, Option<Team>),
+    Start,
+    Back,
+}
+
 fn layer_id() -> egui::LayerId {
     use egui::*;
     LayerId::new(Order::Middle, Id::new("team_select_foreground"))
@@ -35,9 +47,8 @@ pub fn show(world: &World) {
         controller_icon,
         controller_icon_silhouette,
         pad_slot_bg,
-        start,
-        start_blink,
-        back_btn,
+        color_btn_size,
+        back_btn_size,
         back_buffer,
         ..
     } = root.menu.team_select;
@@ -45,6 +56,14 @@ pub fn show(world: &World) {
     let team_select = world.resource::<TeamSelect>();
     let small_inner_font = asset_server.get(root.font.small_inner).family_name.clone();
     let small_outer_font = asset_server.get(root.font.small_outer).family_name.clone();
+    let inner_font = asset_server
+        .get(root.font.primary_inner)
+        .family_name
+        .clone();
+    let outer_font = asset_server
+        .get(root.font.primary_outer)
+        .family_name
+        .clone();
 
     use egui::*;
     let area = Area::new("team_select_area")
@@ -58,6 +77,11 @@ pub fn show(world: &World) {
             });
         });
     let origin = area.response.rect.min;
+    let center_column = Rect::from_min_size(
+        origin + vec2(a_team_background.egui_size().x, 0.),
+        center_controller_column.egui_size(),
+    );
+    let mut actions = Vec::new();
 
     let mut painter = ctx.layer_painter(layer_id());
 
@@ -66,76 +90,37 @@ pub fn show(world: &World) {
         root.screen_size.to_array().into(),
     ));
 
+    let locale = &asset_server.get(world.resource::<Settings>().locale(&root));
+    let ready = locale.get("ready");
+    let not_ready = locale.get("not-ready");
+    let play_both = locale.get("play-both");
+
     // This fixes a glitch with the ui animations when rendering new text.
     // All of it is invisible with the default `Color32`.
-    painter.text(
-        default(),
-        Align2::LEFT_CENTER,
-        "Not Ready",
-        FontId {
-            size: 7.0,
-            family: FontFamily::Name(small_inner_font.clone()),
-        },
-        default(),
-    );
-    painter.text(
-        default(),
-        Align2::LEFT_CENTER,
-        "Not Ready",
-        FontId {
-            size: 7.0,
-            family: FontFamily::Name(small_outer_font.clone()),
-        },
-        default(),
-    );
-    painter.text(
-        default(),
-        Align2::LEFT_CENTER,
-        "Play Both",
-        FontId {
-            size: 7.0,
-            family: FontFamily::Name(small_inner_font.clone()),
-        },
-        default(),
-    );
-    painter.text(
-        default(),
-        Align2::LEFT_CENTER,
-        "Play Both",
-        FontId {
-            size: 7.0,
-            family: FontFamily::Name(small_outer_font.clone()),
-        },
-        default(),
-    );
-    painter.text(
-        default(),
-        Align2::LEFT_CENTER,
-        "Ready!",
-        FontId {
-            size: 7.0,
-            family: FontFamily::Name(small_inner_font.clone()),
-        },
-        default(),
-    );
-    painter.text(
-        default(),
-        Align2::LEFT_CENTER,
-        "Ready!",
-        FontId {
-            size: 7.0,
-            family: FontFamily::Name(small_outer_font.clone()),
-        },
-        default(),
-    );
+    for text in [&not_ready, &play_both, &ready] {
+        for family in [&small_inner_font, &small_outer_font] {
+            painter.text(
+                default(),
+                Align2::LEFT_CENTER,
+                text,
+                FontId {
+                    size: 7.0,
+                    family: FontFamily::Name(family.clone()),
+                },
+                default(),
+            );
+        }
+    }
+
+    let player_pos =
+        |slot: PlayerSlot| slots.get_player_pos(slot, team_select.team_size(slot.team()));
+    let pad_count = team_select.pad_count();
 
     // Pad BGs
-    for player_slot in [
-        PlayerSlot::A1,
-        PlayerSlot::A2,
-        PlayerSlot::B1,
-        PlayerSlot::B2,
-    ] {
+    for player_slot in Team::variants()
+        .into_iter()
+        .flat_map(|team| PlayerSlot::team_variants(team).take(team_select.team_size(team)))
+    {
         let target = if team_select.joins.iter().any(|join| {
             join.is_player_id(player_slot) && join.is_set()
                 || join.is_player_id(player_slot.partner()) && join.is_dual_stick()
@@ -153,24 +138,94 @@ pub fn show(world: &World) {
             .image_painter()
             .pos(origin + slots.pad_bg_offset.to_array().into())
             .size(egui::vec2(x, y))
-            .offset(slots.get_player_pos(player_slot).to_array().into())
+            .offset(player_pos(player_slot).to_array().into())
             .align2(Align2::CENTER_CENTER)
             .paint(&painter, &textures);
     }
 
+    // Team colors
+    for team in Team::variants() {
+        let color = root.sprite.team_color(team_select.color(team));
+        let label = TextPainter::new(locale.get(&color.team).to_uppercase())
+            .size(14.0)
+            .pos(origin + slots.team_label(team).to_array().into())
+            .align2(Align2::CENTER_CENTER);
+        label
+            .clone()
+            .family(inner_font.clone())
+            .color(Color32::WHITE)
+            .paint(&painter);
+        label
+            .clone()
+            .family(outer_font.clone())
+            .color(Color32::BLACK)
+            .paint(&painter);
+
+        let rect = Rect::from_center_size(
+            origin + slots.team_color(team).to_array().into(),
+            color_btn_size.to_array().into(),
+        );
+        painter.rect(
+            rect,
+            rect.height() / 2.0,
+            color.egui_color(),
+            Stroke::new(1.0, color.egui_highlight()),
+        );
+        let builder = TextPainter::new(format!("< {} >", locale.get(&color.name)))
+            .size(7.0)
+            .pos(rect.center())
+            .align2(Align2::CENTER_CENTER);
+        builder
+            .clone()
+            .family(small_inner_font.clone())
+            .color(Color32::WHITE)
+            .paint(&painter);
+        builder
+            .clone()
+            .family(small_outer_font.clone())
+            .color(Color32::BLACK)
+            .paint(&painter);
+        if hit_area(&ctx, ("team_select_color", team), rect).clicked() {
+            actions.push(PointerAction::CycleColor(team));
+        }
+    }
+
     // Pads
     for (index, join) in team_select.joins.iter().enumerate() {
         let player_icon = root.menu.team_select.player_icons()[index];
         let player_slot = join.get_player_slot();
-        let center_slot = slots.pad_slots()[index];
+        let center_slot = slots.pad_slot(index, pad_count);
 
         if let Some(player_slot) = player_slot {
-            let pad_slot = slots.get_player_pos(player_slot);
-            let partner_slot = slots.get_player_pos(player_slot.partner());
+            let pad_slot = player_pos(player_slot);
+            let partner_slot = player_pos(player_slot.partner());
+
+            // outfits, tap to change
+            let color = root
+                .sprite
+                .team_color(team_select.color(player_slot.team()));
+            let mut outfits = vec![(player_slot, pad_slot)];
+            if join.is_dual_stick() {
+                outfits.push((player_slot.partner(), partner_slot));
+            }
+            for (slot, pos) in outfits {
+                let atlas = asset_server.get(color.outfit(team_select.outfit(index, slot)));
+                let rect = AtlasPainter::new(atlas.clone())
+                    .size(atlas.tile_size.to_array().into())
+                    .align2(Align2::CENTER_CENTER)
+                    .pos(origin + (pos + slots.outfit_offset).to_array().into())
+                    .paint(&painter, &textures);
+                if let Some(gamepad) = join.gamepad() {
+                    if hit_area(&ctx, ("team_select_outfit", slot), rect).clicked() {
+                        actions.push(PointerAction::CycleOutfit(gamepad));
+                    }
+                }
+            }
 
             // ready text
+            let mut ready_rect = Rect::NOTHING;
             if join.is_ready() {
-                let builder = TextPainter::new("Ready!")
+                let builder = TextPainter::new(&ready)
                     .size(7.0)
                     .pos(
                         origin
@@ -183,13 +238,13 @@ pub fn show(world: &World) {
                     .family(small_inner_font.clone())
                     .color(Color32::GREEN)
                     .paint(&painter);
-                builder
+                ready_rect = builder
                     .clone()
                     .family(small_outer_font.clone())
                     .color(Color32::BLACK)
                     .paint(&painter);
             } else {
-                let builder = TextPainter::new("Not Ready")
+                let builder = TextPainter::new(&not_ready)
                     .size(7.0)
                     .pos(
                         origin
@@ -202,12 +257,17 @@ pub fn show(world: &World) {
                     .family(small_inner_font.clone())
                     .color(Color32::GRAY)
                     .paint(&painter);
-                builder
+                ready_rect = builder
                     .clone()
                     .family(small_outer_font.clone())
                     .color(Color32::BLACK)
                     .paint(&painter);
             }
+            if let Some(gamepad) = join.gamepad() {
+                if hit_area(&ctx, ("team_select_ready", index), ready_rect.expand(2.0)).clicked() {
+                    actions.push(PointerAction::Reverse(gamepad));
+                }
+            }
             // dual stick ready text
             if join.is_dual_stick() {
                 player_icon.paint_at(
@@ -218,7 +278,7 @@ pub fn show(world: &World) {
                     &textures,
                 );
 
-                let builder = TextPainter::new("Ready!")
+                let builder = TextPainter::new(&ready)
                     .size(7.0)
                     .pos(
                         origin
@@ -266,7 +326,7 @@ pub fn show(world: &World) {
                 && !team_select.is_player_slot_dual_stick(player_slot)
                 && !team_select.is_player_slot_set(player_slot.partner())
             {
-                let builder = TextPainter::new("Play Both")
+                let builder = TextPainter::new(&play_both)
                     .size(7.0)
                     .pos(origin + (partner_slot + slots.ready_text_offset).to_array().into())
                     .align2(Align2::CENTER_CENTER);
@@ -284,9 +344,7 @@ pub fn show(world: &World) {
         }
 
         // animate now so empty joins are returned to center on removal
-        let target = player_slot
-            .map(|slot| slots.get_player_pos(slot))
-            .unwrap_or(*center_slot);
+        let target = player_slot.map(player_pos).unwrap_or(center_slot);
         let x = ctx.animate_value_with_time(Id::new("pad_positions_x").with(index), target.x, 0.2);
         let y = ctx.animate_value_with_time(Id::new("pad_positions_y").with(index), target.y, 0.2);
 
@@ -318,10 +376,42 @@ pub fn show(world: &World) {
                 .image_painter()
                 .pos(rect.min + player_offset)
                 .paint(&painter, &textures);
+
+            // tap to ready up, or drag onto a team
+            let response = hit_area(&ctx, ("team_select_pad", index), rect);
+            // Touches are gone by the time they're released, so remember where the drag was.
+            let drag_pos = response.id.with("drag_pos");
+            if let Some(pointer) = response
+                .interact_pointer_pos()
+                .filter(|_| response.dragged() && join.is_gamepad_id(TOUCH_GAMEPAD))
+            {
+                ctx.data_mut(|data| data.insert_temp(drag_pos, pointer));
+                controller_icon
+                    .image_painter()
+                    .pos(pointer)
+                    .align2(Align2::CENTER_CENTER)
+                    .tint(Color32::WHITE.gamma_multiply(0.5))
+                    .paint(&painter, &textures);
+            }
+            if let Some(gamepad) = join.gamepad() {
+                if response.clicked() {
+                    actions.push(PointerAction::Ready(gamepad));
+                } else if response.drag_released() {
+                    if let Some(pointer) = ctx.data(|data| data.get_temp::<Pos2>(drag_pos)) {
+                        let team = if pointer.x < center_column.left() {
+                            Some(Team::A)
+                        } else if pointer.x > center_column.right() {
+                            Some(Team::B)
+                        } else {
+                            None
+                        };
+                        actions.push(PointerAction::Drop(gamepad, team));
+                    }
+                }
+            }
         }
     }
     // back button
-    let asset = asset_server.get(back_btn);
     let inputs = world.resource::<LocalInputs>();
     let press_input = inputs
         .values()
@@ -335,29 +425,139 @@ pub fn show(world: &World) {
     };
     // one number out of `frames` from 0.0 to 1.0
     let frame_progress = frames as f32 / cap as f32;
-    // one number out of `rows` from 0 to `rows`
-    let index = (frame_progress * asset.rows as f32 - 1.0).floor() as usize;
 
-    AtlasPainter::new(asset.clone())
-        .vertical()
-        .index(index)
-        .pos(origin + slots.back_btn_offset.to_array().into())
-        .paint(&painter, &textures);
+    let rect = Rect::from_min_size(
+        origin + slots.back_btn_offset.to_array().into(),
+        back_btn_size.to_array().into(),
+    );
+    let rounding = rect.height() / 2.0;
+    painter.rect(
+        rect,
+        rounding,
+        Color32::from_gray(34),
+        Stroke::new(1.0, Color32::from_gray(90)),
+    );
+    if frame_progress > 0.0 {
+        painter.rect_filled(
+            Rect::from_min_size(rect.min, vec2(rect.width() * frame_progress, rect.height())),
+            rounding,
+            Color32::from_rgb(190, 38, 51),
+        );
+    }
+    let builder = TextPainter::new(locale.get("go-back"))
+        .size(7.0)
+        .pos(rect.center())
+        .align2(Align2::CENTER_CENTER);
+    builder
+        .clone()
+        .family(small_inner_font.clone())
+        .color(Color32::WHITE)
+        .paint(&painter);
+    builder
+        .clone()
+        .family(small_outer_font.clone())
+        .color(Color32::BLACK)
+        .paint(&painter);
+    if hit_area(&ctx, "team_select_back", rect).clicked() {
+        actions.push(PointerAction::Back);
+    }
 
     // press start text
+    let mut start = Rect::NOTHING;
     if team_select.get_player_signs().is_some() {
-        if world.resource::<Time>().elapsed().as_secs_f32() % 1.0 < 0.5 {
-            start.paint_at(
-                origin + slots.start_offset.to_array().into(),
-                &painter,
-                &textures,
-            );
-        } else {
-            start_blink.paint_at(
-                origin + slots.start_offset.to_array().into(),
-                &painter,
-                &textures,
-            );
+        let inner_font = asset_server
+            .get(root.font.primary_inner)
+            .family_name
+            .clone();
+        let outer_font = asset_server
+            .get(root.font.primary_outer)
+            .family_name
+            .clone();
+        let blink = world.resource::<Time>().elapsed().as_secs_f32() % 1.0 < 0.5;
+
+        for builder in [
+            TextPainter::standard().family(inner_font).color(if blink {
+                Color32::WHITE
+            } else {
+                Color32::YELLOW
+            }),
+            TextPainter::standard().family(outer_font),
+        ] {
+            start = builder
+                .size(14.0)
+                .text(locale.get("press-start"))
+                .align2(Align2::CENTER_CENTER)
+                .pos(origin + slots.start_offset.to_array().into())
+                .paint(&painter);
+        }
+    }
+    if hit_area(&ctx, "team_select_start", start.expand(2.0)).clicked() {
+        actions.push(PointerAction::Start);
+    }
+    drop(team_select);
+
+    if *world.resource::<MenuState>() != MenuState::TeamSelect
+        || !world.resource::<TeamSelect>().nav.can_use_pointer()
+    {
+        return;
+    }
+    for action in actions {
+        let mut team_select = world.resource_mut::<TeamSelect>();
+        match action {
+            // The pointer is the touch controls' pad, so like every pad it only moves itself.
+            PointerAction::Ready(gamepad)
+            | PointerAction::Reverse(gamepad)
+            | PointerAction::CycleOutfit(gamepad)
+            | PointerAction::Drop(gamepad, _)
+                if gamepad != TOUCH_GAMEPAD => {}
+            PointerAction::Ready(gamepad) => team_select.ready_gamepad(gamepad),
+            PointerAction::Reverse(gamepad) => team_select.reverse_gamepad(gamepad),
+            PointerAction::CycleOutfit(gamepad) => team_select.cycle_outfit(gamepad),
+            PointerAction::CycleColor(team) => {
+                if team_select.gamepad_team(TOUCH_GAMEPAD) == Some(team) {
+                    team_select.cycle_color(team, &root.sprite);
+                }
+            }
+            // Moving a controller is only allowed before it's ready, like with the stick.
+            // Going over twice lets it cross from one team to the other.
+            PointerAction::Drop(gamepad, Some(Team::A)) => {
+                team_select.left_gamepad(gamepad);
+                team_select.left_gamepad(gamepad);
+            }
+            PointerAction::Drop(gamepad, Some(Team::B)) => {
+                team_select.right_gamepad(gamepad);
+                team_select.right_gamepad(gamepad);
+            }
+            PointerAction::Drop(gamepad, None) => match team_select.gamepad_team(gamepad) {
+                Some(Team::A) => team_select.right_gamepad(gamepad),
+                Some(Team::B) => team_select.left_gamepad(gamepad),
+                None => {}
+            },
+            PointerAction::Start => {
+                drop(team_select);
+                world.resource_mut::<ArenaSelect>().open(Maybe::Unset);
+                start_fade(
+                    world,
+                    FadeTransition {
+                        hide: team_select_hide,
+                        prep: arena_select_prep,
+                        finish: arena_select_finish,
+                    },
+                );
+                return;
+            }
+            PointerAction::Back => {
+                drop(team_select);
+                start_fade(
+                    world,
+                    FadeTransition {
+                        hide: team_select_hide,
+                        prep: splash_prep,
+                        finish: splash_finish,
+                    },
+                );
+                return;
+            }
         }
     }
 }
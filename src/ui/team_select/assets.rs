@@ -14,23 +14,30 @@ pub struct TeamSelectAssets {
     pub player2_icon: SizedImageAsset,
     pub player3_icon: SizedImageAsset,
     pub player4_icon: SizedImageAsset,
+    pub player5_icon: SizedImageAsset,
+    pub player6_icon: SizedImageAsset,
+    pub player7_icon: SizedImageAsset,
+    pub player8_icon: SizedImageAsset,
 
     pub controller_icon: SizedImageAsset,
     pub controller_icon_silhouette: SizedImageAsset,
 
-    pub start: SizedImageAsset,
-    pub start_blink: SizedImageAsset,
-
-    pub back_btn: Handle<Atlas>,
+    pub color_btn_size: Vec2,
+    pub back_btn_size: Vec2,
+    /// The frames the back button has to be held to go back.
     pub back_buffer: u32,
 }
 impl TeamSelectAssets {
-    pub fn player_icons(&self) -> [&SizedImageAsset; 4] {
+    pub fn player_icons(&self) -> [&SizedImageAsset; PlayerSlot::COUNT] {
         [
             &self.player1_icon,
             &self.player2_icon,
             &self.player3_icon,
             &self.player4_icon,
+            &self.player5_icon,
+            &self.player6_icon,
+            &self.player7_icon,
+            &self.player8_icon,
         ]
     }
 }
@@ -38,36 +45,75 @@ impl TeamSelectAssets {
 #[derive(HasSchema, Clone, Copy, Default)]
 #[repr(C)]
 pub struct TeamSelectSlots {
-    pub pad1: Vec2,
-    pub pad2: Vec2,
-    pub pad3: Vec2,
-    pub pad4: Vec2,
+    /// Where the first controller waits in the center column.
+    pub pad_top: Vec2,
+    /// How far down the center column the last controller can wait.
+    pub pad_bottom: f32,
+    /// The space between waiting controllers, squeezed when more of them don't fit.
+    pub pad_spacing: f32,
 
-    pub a1: Vec2,
-    pub a2: Vec2,
-    pub b1: Vec2,
-    pub b2: Vec2,
+    /// The x of each column of team A's slots, one for each dual stick pair.
+    pub a_columns: Vec2,
+    /// The x of each column of team B's slots, one for each dual stick pair.
+    pub b_columns: Vec2,
+    /// The y of the first & second slot of each pair.
+    pub rows: Vec2,
     pub pad_bg_offset: Vec2,
     pub number_icon_offset: Vec2,
     pub ready_text_offset: Vec2,
     pub ready_btn_offset: Vec2,
+    /// The center of the outfit a player wears, relative to their slot.
+    pub outfit_offset: Vec2,
+    /// The center of team A's name.
+    pub a_label: Vec2,
+    /// The center of team B's name.
+    pub b_label: Vec2,
+    /// The center of team A's color.
+    pub a_color: Vec2,
+    /// The center of team B's color.
+    pub b_color: Vec2,
 
+    /// The center of the press start text.
     pub start_offset: Vec2,
     pub back_btn_offset: Vec2,
 }
 impl TeamSelectSlots {
-    pub fn pad_slots(&self) -> [&Vec2; 4] {
-        [&self.pad1, &self.pad2, &self.pad3, &self.pad4]
+    /// Where the controller at `index` waits in the center column while `count` of them are.
+    pub fn pad_slot(&self, index: usize, count: usize) -> Vec2 {
+        let room = (self.pad_bottom - self.pad_top.y) / count.saturating_sub(1).max(1) as f32;
+        self.pad_top + Vec2::Y * self.pad_spacing.min(room) * index as f32
+    }
+    pub fn team_label(&self, team: Team) -> Vec2 {
+        match team {
+            Team::A => self.a_label,
+            Team::B => self.b_label,
+        }
     }
-    pub fn get_player_pos(&self, player_slot: PlayerSlot) -> Vec2 {
-        match player_slot {
-            PlayerSlot::A1 => self.a1,
-            PlayerSlot::A2 => self.a2,
-            PlayerSlot::B1 => self.b1,
-            PlayerSlot::B2 => self.b2,
+    pub fn team_color(&self, team: Team) -> Vec2 {
+        match team {
+            Team::A => self.a_color,
+            Team::B => self.b_color,
         }
     }
-    pub fn player_slots(&self) -> [&Vec2; 4] {
-        [&self.a1, &self.a2, &self.b1, &self.b2]
+    /// Where `player_slot` is on a side laid out for `team_size` slots.
+    /// A side with a single pair is centered between the columns.
+    pub fn get_player_pos(&self, player_slot: PlayerSlot, team_size: usize) -> Vec2 {
+        let columns = match player_slot.team() {
+            Team::A => self.a_columns,
+            Team::B => self.b_columns,
+        };
+        let x = if team_size <= 2 {
+            (columns.x + columns.y) / 2.
+        } else if player_slot.n < 2 {
+            columns.x
+        } else {
+            columns.y
+        };
+        let y = if player_slot.is_primary() {
+            self.rows.x
+        } else {
+            self.rows.y
+        };
+        Vec2::new(x, y)
     }
 }
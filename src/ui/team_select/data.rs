@@ -92,6 +92,23 @@ impl Join {
             panic!("un-enforced join state ordering");
         }
     }
+    /// Hands the join over to another controller, keeping its progress.
+    pub fn set_gamepad(&mut self, id: u32) {
+        match self {
+            Join::Empty => {}
+            Join::Joined { gamepad } | Join::Set { gamepad, .. } | Join::Ready { gamepad, .. } => {
+                *gamepad = id
+            }
+        }
+    }
+    pub fn gamepad(&self) -> Option<u32> {
+        match self {
+            Join::Empty => None,
+            Join::Joined { gamepad } | Join::Set { gamepad, .. } | Join::Ready { gamepad, .. } => {
+                Some(*gamepad)
+            }
+        }
+    }
     pub fn get_player_slot(&self) -> Option<PlayerSlot> {
         match &self {
             Join::Empty | Join::Joined { .. } => None,
@@ -131,12 +148,80 @@ impl Join {
     }
 }
 
-#[derive(HasSchema, Clone, Default)]
+#[derive(HasSchema, Clone)]
 pub struct TeamSelect {
     pub visible: bool,
-    pub joins: [Join; 4],
+    /// A join for every slot, so a controller for each character can play.
+    pub joins: [Join; PlayerSlot::COUNT],
+    /// The color of team A & team B, from [`Sprites::team_colors`].
+    pub colors: [usize; 2],
+    /// How many outfits past their slot's own each join has picked, so teammates start apart.
+    pub outfits: [usize; PlayerSlot::COUNT],
+    /// Filters & repeats the controllers' input. It has no items to focus,
+    /// every controller moves its own pad.
+    pub nav: MenuNav,
+}
+impl Default for TeamSelect {
+    fn default() -> Self {
+        Self {
+            visible: false,
+            joins: default(),
+            colors: [0, 1],
+            outfits: default(),
+            nav: default(),
+        }
+    }
 }
 impl TeamSelect {
+    pub fn color(&self, team: Team) -> usize {
+        match team {
+            Team::A => self.colors[0],
+            Team::B => self.colors[1],
+        }
+    }
+    /// Moves `team` on to the next color that can be told apart from the other team's.
+    pub fn cycle_color(&mut self, team: Team, sprites: &Sprites) {
+        let count = sprites.team_colors.len();
+        let current = self.color(team);
+        let other = self.color(team.other());
+        let Some(next) = (1..count)
+            .map(|step| (current + step) % count)
+            .find(|color| !sprites.team_colors_clash(*color, other))
+        else {
+            return;
+        };
+        match team {
+            Team::A => self.colors[0] = next,
+            Team::B => self.colors[1] = next,
+        }
+    }
+    /// The team the gamepad picked, if it's on one.
+    pub fn gamepad_team(&self, id: u32) -> Option<Team> {
+        self.joins
+            .iter()
+            .find(|join| join.is_gamepad_id(id))
+            .and_then(Join::get_player_slot)
+            .map(|slot| slot.team())
+    }
+    /// Changes the color of the team the gamepad picked.
+    pub fn cycle_gamepad_color(&mut self, id: u32, sprites: &Sprites) {
+        if let Some(team) = self.gamepad_team(id) {
+            self.cycle_color(team, sprites);
+        }
+    }
+    /// Changes the outfit of the gamepad once it's picked a team.
+    pub fn cycle_outfit(&mut self, id: u32) {
+        let Some(index) = self.get_index_from_gamepad(id) else {
+            return;
+        };
+        if self.joins[index].is_set() {
+            self.outfits[index] += 1;
+        }
+    }
+    /// The outfit the join at `index` wears in `slot`.
+    pub fn outfit(&self, index: usize, slot: PlayerSlot) -> usize {
+        slot.outfit(self.outfits[index])
+    }
     pub fn add_gamepad(&mut self, id: u32) {
         if !self.joins.iter().any(|join| join.is_gamepad_id(id)) {
             for pad in &mut self.joins {
@@ -216,56 +301,36 @@ impl TeamSelect {
             }
         }
     }
-    pub fn next_slot_a(&self) -> Option<PlayerSlot> {
-        let mut a1 = false;
-        let mut a2 = false;
-        for join in &self.joins {
-            if join.is_set() {
-                if join.is_player_id(PlayerSlot::A1) {
-                    a1 = true;
-                    if join.is_dual_stick() {
-                        a2 = true
-                    }
-                }
-                if join.is_player_id(PlayerSlot::A2) {
-                    a2 = true;
-                }
-            }
-        }
-        if !a1 {
-            return PlayerSlot::A1.into();
-        }
-        if !a2 {
-            return PlayerSlot::A2.into();
-        }
-        None
-    }
-    pub fn next_slot_b(&self) -> Option<PlayerSlot> {
-        let mut b1 = false;
-        let mut b2 = false;
-        for join in &self.joins {
-            if join.is_set() {
-                if join.is_player_id(PlayerSlot::B1) {
-                    b1 = true;
-                    if join.is_dual_stick() {
-                        b2 = true
-                    }
-                }
-                if join.is_player_id(PlayerSlot::B2) {
-                    b2 = true;
-                }
-            }
-        }
-        if !b1 {
-            return PlayerSlot::B1.into();
-        }
-        if !b2 {
-            return PlayerSlot::B2.into();
-        }
-        None
+    /// How many slots the team's side is laid out for, a pair for every pair up to the
+    /// last one taken so small teams aren't spread out.
+    pub fn team_size(&self, team: Team) -> usize {
+        let taken = PlayerSlot::team_variants(team)
+            .filter(|slot| {
+                self.is_player_slot_set(*slot) || self.is_player_slot_dual_stick(slot.partner())
+            })
+            .map(|slot| slot.n as usize + 1)
+            .max()
+            .unwrap_or(0);
+        (taken.max(1) + 1) / 2 * 2
+    }
+    /// How many controllers wait in the center column, up to the last one that joined.
+    pub fn pad_count(&self) -> usize {
+        self.joins
+            .iter()
+            .rposition(Join::is_joined)
+            .map_or(0, |index| index + 1)
+    }
+    /// The first slot of the team that nobody has taken, including with dual stick.
+    pub fn next_slot(&self, team: Team) -> Option<PlayerSlot> {
+        PlayerSlot::team_variants(team).find(|slot| {
+            !self.joins.iter().any(|join| {
+                join.is_set() && join.is_player_id(*slot)
+                    || join.is_dual_stick() && join.is_player_id(slot.partner())
+            })
+        })
     }
     pub fn left_gamepad(&mut self, id: u32) {
-        let next_slot_a = self.next_slot_a();
+        let next_slot_a = self.next_slot(Team::A);
         for join in &mut self.joins {
             if let Join::Set {
                 gamepad,
@@ -285,7 +350,7 @@ impl TeamSelect {
         }
     }
     pub fn right_gamepad(&mut self, id: u32) {
-        let next_slot_b = self.next_slot_b();
+        let next_slot_b = self.next_slot(Team::B);
         for join in &mut self.joins {
             if let Join::Set {
                 gamepad,
@@ -328,100 +393,46 @@ impl TeamSelect {
             .iter()
             .any(|join| join.is_player_id(id) && join.is_set())
     }
+    /// The players of each team once everyone who picked a team is ready,
+    /// and both teams have at least one player.
     pub fn get_player_signs(&self) -> Option<PlayersInfo> {
-        let mut builder = PlayerInfoBuilder::default();
+        let mut players = PlayersInfo {
+            team_a: TeamInfo {
+                color: self.color(Team::A),
+                ..default()
+            },
+            team_b: TeamInfo {
+                color: self.color(Team::B),
+                ..default()
+            },
+        };
 
         for (number, join) in self.joins.iter().enumerate() {
-            if let Join::Ready {
-                gamepad,
-                slot,
-                dual_stick,
-            } = *join
-            {
-                builder.insert(PlayerInfo {
-                    number,
+            match *join {
+                Join::Ready {
                     gamepad,
-                    dual_stick,
                     slot,
-                });
-            }
-        }
-        builder.finish()
-    }
-}
-
-#[derive(Default)]
-pub struct PlayerInfoBuilder {
-    team_a: TeamInfoBuilder,
-    team_b: TeamInfoBuilder,
-}
-impl PlayerInfoBuilder {
-    fn insert(&mut self, player: PlayerInfo) {
-        let builder = match player.slot.team() {
-            Team::A => &mut self.team_a,
-            Team::B => &mut self.team_b,
-        };
-        if player.dual_stick {
-            builder.insert_dual_stick(player);
-        } else if player.slot.is_primary() {
-            builder.insert_primary(player);
-        } else {
-            builder.insert_secondary(player);
-        }
-    }
-    fn finish(self) -> Option<PlayersInfo> {
-        Some(PlayersInfo {
-            team_a: self.team_a.finish()?,
-            team_b: self.team_b.finish()?,
-        })
-    }
-}
-
-#[derive(Clone, Copy, Default)]
-enum TeamInfoBuilder {
-    #[default]
-    Empty,
-    Primary(PlayerInfo),
-    Secondary(PlayerInfo),
-    Single(PlayerInfo),
-    Double(PlayerInfo, PlayerInfo),
-}
-impl TeamInfoBuilder {
-    fn insert_dual_stick(&mut self, player: PlayerInfo) {
-        *self = match *self {
-            Self::Empty => Self::Single(player),
-            Self::Secondary(..) | Self::Primary(..) | Self::Single(..) | Self::Double(..) => {
-                panic!("team slot taken twice")
-            }
-        }
-    }
-    fn insert_primary(&mut self, player: PlayerInfo) {
-        *self = match *self {
-            Self::Empty => Self::Primary(player),
-            Self::Secondary(secondary) => Self::Double(player, secondary),
-            Self::Primary(..) | Self::Single(..) | Self::Double(..) => {
-                panic!("team slot taken twice")
-            }
-        }
-    }
-    fn insert_secondary(&mut self, player: PlayerInfo) {
-        *self = match *self {
-            Self::Empty => Self::Secondary(player),
-            Self::Primary(primary) => Self::Double(primary, player),
-            Self::Secondary(..) | Self::Single(..) | Self::Double(..) => {
-                panic!("team slot taken twice")
+                    dual_stick,
+                } => {
+                    let team = match slot.team() {
+                        Team::A => &mut players.team_a,
+                        Team::B => &mut players.team_b,
+                    };
+                    team.players.push(PlayerInfo {
+                        number,
+                        gamepad,
+                        dual_stick,
+                        slot,
+                        picked_outfit: self.outfits[number],
+                    });
+                }
+                Join::Set { .. } => return None,
+                Join::Empty | Join::Joined { .. } => {}
             }
         }
-    }
-    fn finish(self) -> Option<TeamInfo> {
-        match self {
-            TeamInfoBuilder::Empty
-            | TeamInfoBuilder::Primary(..)
-            | TeamInfoBuilder::Secondary(..) => None,
-            TeamInfoBuilder::Single(primary) => TeamInfo::Single(primary).into(),
-            TeamInfoBuilder::Double(primary, secondary) => {
-                TeamInfo::Double(primary, secondary).into()
-            }
+        if players.team_a.players.is_empty() || players.team_b.players.is_empty() {
+            return None;
         }
+        Some(players)
     }
 }
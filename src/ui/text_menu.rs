@@ -0,0 +1,100 @@
+use super::*;
+
+/// The layout of a framed list of options, written out so they can be localized.
+#[derive(HasSchema, Clone, Copy, Default)]
+#[repr(C)]
+pub struct TextMenuAssets {
+    pub size: Vec2,
+    /// The center of the title, relative to the top left of the frame.
+    pub title: Vec2,
+    /// The center of the first option, relative to the top left of the frame.
+    pub first_option: Vec2,
+    pub option_height: f32,
+    pub cursor: SizedImageAsset,
+    /// Where the cursor is drawn relative to the left middle of the selected option.
+    pub cursor_offset: Vec2,
+}
+
+/// Draws the menu in the middle of the screen with the cursor next to the focused option.
+pub fn show_text_menu(
+    world: &World,
+    id: &str,
+    menu: &TextMenuAssets,
+    title: Option<&str>,
+    options: &[&str],
+    nav: &mut MenuNav,
+) {
+    let ctx = world.resource::<EguiCtx>();
+    let textures = world.resource::<EguiTextures>();
+    let asset_server = world.resource::<AssetServer>();
+    let root = asset_server.root::<Data>();
+
+    let inner_font = asset_server
+        .get(root.font.primary_inner)
+        .family_name
+        .clone();
+    let outer_font = asset_server
+        .get(root.font.primary_outer)
+        .family_name
+        .clone();
+
+    use egui::*;
+
+    let inner = TextPainter::standard()
+        .size(7.0)
+        .family(inner_font)
+        .color(Color32::WHITE)
+        .align2(Align2::CENTER_CENTER);
+    let outer = TextPainter::standard()
+        .size(7.0)
+        .family(outer_font)
+        .align2(Align2::CENTER_CENTER);
+
+    Area::new(id)
+        .anchor(Align2::CENTER_CENTER, [0., 0.])
+        .order(Order::Foreground)
+        .show(&ctx, |ui| {
+            let (rect, _) = ui.allocate_exact_size(menu.size.to_array().into(), Sense::hover());
+            let painter = ui.painter();
+            painter.rect(
+                rect,
+                4.0,
+                Color32::from_gray(34),
+                Stroke::new(1.0, Color32::from_gray(90)),
+            );
+
+            if let Some(title) = title {
+                for builder in [&inner, &outer] {
+                    builder
+                        .clone()
+                        .size(14.0)
+                        .text(title)
+                        .pos(rect.min + menu.title.to_array().into())
+                        .paint(painter);
+                }
+            }
+            for (i, option) in options.iter().enumerate() {
+                let pos = rect.min
+                    + menu.first_option.to_array().into()
+                    + vec2(0., menu.option_height * i as f32);
+                let mut text_rect = Rect::NOTHING;
+                for builder in [&inner, &outer] {
+                    text_rect = builder.clone().text(option).pos(pos).paint(painter);
+                }
+                let row = Rect::from_center_size(
+                    pos2(rect.center().x, pos.y),
+                    vec2(rect.width(), menu.option_height),
+                );
+                nav.pointer(i, &ui.interact(row, ui.id().with(i), Sense::click()));
+                if i == nav.focus {
+                    menu.cursor.paint_at(
+                        text_rect.left_center()
+                            + menu.cursor_offset.to_array().into()
+                            + vec2(-menu.cursor.egui_size().x, -menu.cursor.egui_size().y / 2.),
+                        painter,
+                        &textures,
+                    );
+                }
+            }
+        });
+}
@@ -0,0 +1,171 @@
+use super::*;
+
+/// The layout of the on-screen controls for playing without a controller.
+#[derive(HasSchema, Clone, Copy, Default)]
+#[repr(C)]
+pub struct TouchControlsAssets {
+    /// The center of the stick, relative to the bottom left of the screen.
+    pub stick: Vec2,
+    pub stick_radius: f32,
+    pub knob_radius: f32,
+    /// The center of the shoot button, relative to the bottom right of the screen.
+    pub shoot: Vec2,
+    /// The center of the pass button, relative to the bottom right of the screen.
+    pub pass: Vec2,
+    pub button_radius: f32,
+    /// The center of the start button, relative to the top middle of the screen.
+    pub start: Vec2,
+    pub start_radius: f32,
+}
+
+/// The fingers on the screen, read into the [`TouchInput`].
+#[derive(HasSchema, Clone, Default)]
+pub struct TouchControls {
+    /// Turned on by the first touch so the controls stay hidden for a mouse or controller.
+    pub enabled: bool,
+    /// Where each finger is on the screen, by its [`egui::TouchId`].
+    pub touches: SMap<u64, Vec2>,
+    /// The finger moving the stick, which is the first one to touch it.
+    pub stick_touch: Maybe<u64>,
+}
+impl SessionPlugin for TouchControls {
+    fn install(self, session: &mut SessionBuilder) {
+        session.insert_resource(self);
+    }
+}
+
+fn layer_id() -> egui::LayerId {
+    use egui::*;
+    LayerId::new(Order::Foreground, Id::new("touch_controls"))
+}
+
+pub fn show(world: &World) {
+    let ctx = world.resource::<EguiCtx>();
+    let asset_server = world.resource::<AssetServer>();
+    let root = asset_server.root::<Data>();
+    let layout = root.menu.touch_controls;
+
+    use egui::*;
+
+    let screen = ctx.screen_rect();
+    let stick_center = screen.left_bottom() + layout.stick.to_array().into();
+    let shoot_center = screen.right_bottom() + layout.shoot.to_array().into();
+    let pass_center = screen.right_bottom() + layout.pass.to_array().into();
+    let start_center = screen.center_top() + layout.start.to_array().into();
+
+    let mut controls = world.resource_mut::<TouchControls>();
+    for event in ctx.input(|input| input.events.clone()) {
+        let Event::Touch { id, phase, pos, .. } = event else {
+            continue;
+        };
+        controls.enabled = true;
+        match phase {
+            TouchPhase::Start | TouchPhase::Move => {
+                controls
+                    .touches
+                    .insert(id.0, pos.to_vec2().to_array().into());
+            }
+            TouchPhase::End | TouchPhase::Cancel => {
+                controls.touches.remove(&id.0);
+                if controls.stick_touch.option() == Some(id.0) {
+                    controls.stick_touch = Maybe::Unset;
+                }
+            }
+        }
+        // A little leeway, since the thumb is bigger than the stick.
+        if phase == TouchPhase::Start
+            && controls.stick_touch.option().is_none()
+            && pos.distance(stick_center) <= layout.stick_radius * 1.5
+        {
+            controls.stick_touch = Maybe::Set(id.0);
+        }
+    }
+
+    // The touch gamepad is used to join team select and to play, the menus take the pointer.
+    let visible = controls.enabled
+        && matches!(
+            *world.resource::<MenuState>(),
+            MenuState::TeamSelect | MenuState::InGame
+        );
+    if !visible {
+        *world.resource_mut::<TouchInput>() = TouchInput {
+            active: controls.enabled,
+            ..default()
+        };
+        return;
+    }
+
+    let touches = controls
+        .touches
+        .iter()
+        .map(|(_, pos)| pos2(pos.x, pos.y))
+        .collect::<Vec<_>>();
+    let pressed =
+        |center: Pos2, radius: f32| touches.iter().any(|pos| pos.distance(center) <= radius);
+    // Screen coordinates go down while the stick goes up.
+    let stick = controls
+        .stick_touch
+        .option()
+        .and_then(|id| controls.touches.get(&id))
+        .map(|pos| {
+            let offset = (pos2(pos.x, pos.y) - stick_center) / layout.stick_radius;
+            let offset = offset / offset.length().max(1.0);
+            bones::Vec2::new(offset.x, -offset.y)
+        })
+        .unwrap_or_default();
+    let input = TouchInput {
+        active: true,
+        stick,
+        shoot: pressed(shoot_center, layout.button_radius),
+        pass: pressed(pass_center, layout.button_radius),
+        start: pressed(start_center, layout.start_radius),
+    };
+    drop(controls);
+    *world.resource_mut::<TouchInput>() = input;
+
+    let locale = &asset_server.get(world.resource::<Settings>().locale(&root));
+    let inner_font = asset_server.get(root.font.small_inner).family_name.clone();
+    let outer_font = asset_server.get(root.font.small_outer).family_name.clone();
+    let painter = ctx.layer_painter(layer_id());
+    let fill = |pressed: bool| Color32::WHITE.gamma_multiply(if pressed { 0.4 } else { 0.15 });
+    let stroke = Stroke::new(1.0, Color32::WHITE.gamma_multiply(0.5));
+
+    painter.circle(stick_center, layout.stick_radius, fill(false), stroke);
+    painter.circle(
+        stick_center + vec2(stick.x, -stick.y) * layout.stick_radius,
+        layout.knob_radius,
+        fill(stick != bones::Vec2::ZERO),
+        stroke,
+    );
+    for (center, radius, pressed, key) in [
+        (
+            shoot_center,
+            layout.button_radius,
+            input.shoot,
+            "touch-shoot",
+        ),
+        (pass_center, layout.button_radius, input.pass, "touch-pass"),
+        (
+            start_center,
+            layout.start_radius,
+            input.start,
+            "touch-start",
+        ),
+    ] {
+        painter.circle(center, radius, fill(pressed), stroke);
+        let builder = TextPainter::new(locale.get(key))
+            .size(7.0)
+            .pos(center)
+            .align2(Align2::CENTER_CENTER);
+        builder
+            .clone()
+            .family(inner_font.clone())
+            .color(Color32::WHITE)
+            .paint(&painter);
+        builder
+            .clone()
+            .family(outer_font.clone())
+            .color(Color32::BLACK)
+            .paint(&painter);
+    }
+}
@@ -1,10 +1,11 @@
 use super::*;
 
-#[derive(HasSchema, Clone, Default)]
+#[derive(HasSchema, Clone, Copy, Default)]
 #[repr(C)]
 pub struct WinnerBannerAssets {
-    pub team_a: SizedImageAsset,
-    pub team_b: SizedImageAsset,
+    pub size: Vec2,
+    /// The stripe drawn along the top & bottom of the banner.
+    pub border: f32,
 }
 
 #[derive(HasSchema, Clone, Default)]
@@ -29,21 +30,48 @@ pub fn show(world: &World) {
     }
     let asset_server = world.resource::<AssetServer>();
     let root = asset_server.root::<Data>();
+    let locale = &asset_server.get(world.resource::<Settings>().locale(&root));
+    let WinnerBannerAssets { size, border } = root.menu.winner_banner;
+
+    let inner_font = asset_server
+        .get(root.font.primary_inner)
+        .family_name
+        .clone();
+    let outer_font = asset_server
+        .get(root.font.primary_outer)
+        .family_name
+        .clone();
+
+    let color = root
+        .sprite
+        .team_color(world.resource::<PlayMode>().team_color(winner.team));
+    let (fill, stripe, text) = (
+        color.egui_color(),
+        color.egui_highlight(),
+        locale.get(&color.wins),
+    );
 
     use egui::*;
     Area::new("match-done-ui")
         .anchor(Align2::CENTER_CENTER, [0., 0.])
         .show(&world.resource::<EguiCtx>(), |ui| {
-            let egui_textures = world.resource::<EguiTextures>();
-            ui.horizontal(|ui| {
-                let banner = match winner.team {
-                    Team::A => &root.menu.winner_banner.team_a,
-                    Team::B => &root.menu.winner_banner.team_b,
-                };
-                ui.image(ImageSource::Texture(load::SizedTexture::new(
-                    egui_textures.get(**banner),
-                    banner.egui_size(),
-                )));
-            });
+            let (rect, _) = ui.allocate_exact_size(size.to_array().into(), Sense::hover());
+            let painter = ui.painter();
+            painter.rect_filled(rect, 0.0, stripe);
+            painter.rect_filled(rect.shrink2(vec2(0., border)), 0.0, fill);
+
+            for builder in [
+                TextPainter::standard()
+                    .family(inner_font)
+                    .color(Color32::WHITE),
+                TextPainter::standard().family(outer_font),
+            ] {
+                builder
+                    .size(21.0)
+                    .text(&text)
+                    .align2(Align2::CENTER_CENTER)
+                    .pos(rect.center())
+                    .paint(painter);
+            }
         });
 }
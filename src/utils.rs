@@ -8,15 +8,19 @@
 mod egui;
 mod follow;
 mod input;
+mod interpolate;
 mod lifetime;
 mod path2d;
+mod rng;
 mod state;
 
 pub use egui::*;
 pub use follow::*;
 pub use input::*;
+pub use interpolate::*;
 pub use lifetime::*;
 pub use path2d::*;
+pub use rng::*;
 pub use state::*;
 
 use bones_framework::prelude::*;
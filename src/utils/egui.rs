@@ -241,3 +241,173 @@ impl TextPainter {
         )
     }
 }
+
+/// The buttons of one controller that drive a [`MenuNav`].
+#[derive(Clone, Copy, Default)]
+pub struct MenuInput {
+    pub up: super::PressInput,
+    pub down: super::PressInput,
+    pub left: super::PressInput,
+    pub right: super::PressInput,
+    pub confirm: super::PressInput,
+    pub back: super::PressInput,
+}
+
+/// What a [`MenuNav`] was asked to do on an update.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum MenuEvent {
+    /// The item at the index was chosen.
+    Confirm(usize),
+    Back,
+    /// Left (`-1`) or right (`1`) was pressed on the item at the index.
+    Adjust(usize, i32),
+}
+
+/// The focus of a vertical list of menu items.
+///
+/// Directions wrap around and repeat while held, clicks & hovers
+/// are fed in with [`Self::pointer`] while the items are drawn.
+/// The pointer counts as the touch controls' [`TOUCH_GAMEPAD`](crate::TOUCH_GAMEPAD),
+/// so it's ignored while another controller owns the menu.
+#[derive(HasSchema, Clone, Copy, Default, Debug)]
+pub struct MenuNav {
+    pub focus: usize,
+    pub len: usize,
+    /// The only controller that can use the menu when set.
+    pub owner: Maybe<u32>,
+    /// The last controller to press anything on the menu,
+    /// for items that change something of the controller's own.
+    pub user: Maybe<u32>,
+    /// A click waiting to be read by the next [`Self::update`].
+    clicked: Option<MenuEvent>,
+}
+impl MenuNav {
+    /// The frames a direction is held before it starts repeating.
+    pub const REPEAT_DELAY: u32 = 24;
+    /// The frames between each repeat of a held direction.
+    pub const REPEAT_FRAMES: u32 = 6;
+
+    pub fn new(len: usize) -> Self {
+        Self { len, ..default() }
+    }
+    pub fn focus_next(&mut self) {
+        self.focus = (self.focus + 1) % self.len.max(1);
+    }
+    pub fn focus_prev(&mut self) {
+        self.focus = (self.focus + self.len.max(1) - 1) % self.len.max(1);
+    }
+    pub fn can_use(&self, id: u32) -> bool {
+        self.owner.option().map_or(true, |owner| owner == id)
+    }
+    /// Whether `input` was just pressed or is being repeated by holding it.
+    pub fn repeated(input: &super::PressInput) -> bool {
+        input.just_pressed()
+            || input.just_held(Self::REPEAT_DELAY)
+                && (input.held() - Self::REPEAT_DELAY) % Self::REPEAT_FRAMES == 0
+    }
+    /// Moves the focus with the `inputs` of the controllers allowed to use the menu,
+    /// returning the first confirm, back or adjust.
+    pub fn update(
+        &mut self,
+        inputs: impl IntoIterator<Item = (u32, MenuInput)>,
+    ) -> Option<MenuEvent> {
+        if let Some(event) = self.clicked.take().filter(|_| self.can_use_pointer()) {
+            if let MenuEvent::Confirm(index) | MenuEvent::Adjust(index, _) = event {
+                self.focus = index;
+            }
+            return Some(event);
+        }
+        for (id, input) in inputs {
+            if !self.can_use(id) {
+                continue;
+            }
+            let MenuInput {
+                up,
+                down,
+                left,
+                right,
+                confirm,
+                back,
+            } = input;
+            if [up, down, left, right, confirm, back]
+                .iter()
+                .any(|input| input.just_pressed())
+            {
+                self.user = Maybe::Set(id);
+            }
+            if Self::repeated(&input.down) {
+                self.focus_next();
+            }
+            if Self::repeated(&input.up) {
+                self.focus_prev();
+            }
+            if input.confirm.just_pressed() {
+                return Some(MenuEvent::Confirm(self.focus));
+            }
+            if input.back.just_pressed() {
+                return Some(MenuEvent::Back);
+            }
+            if Self::repeated(&input.left) {
+                return Some(MenuEvent::Adjust(self.focus, -1));
+            }
+            if Self::repeated(&input.right) {
+                return Some(MenuEvent::Adjust(self.focus, 1));
+            }
+        }
+        None
+    }
+    pub fn can_use_pointer(&self) -> bool {
+        self.can_use(crate::TOUCH_GAMEPAD)
+    }
+    /// Focuses the item at `index` when the pointer moves over it and confirms it when clicked.
+    pub fn pointer(&mut self, index: usize, response: &Response) {
+        self.pointer_hover(index, response);
+        if response.clicked() && self.can_use_pointer() {
+            self.clicked = Some(MenuEvent::Confirm(index));
+        }
+    }
+    /// Like [`Self::pointer`], but a click adjusts the item instead,
+    /// down on its left half and up on its right half.
+    pub fn pointer_adjust(&mut self, index: usize, response: &Response) {
+        self.pointer_hover(index, response);
+        if response.clicked() && self.can_use_pointer() {
+            let left = response
+                .interact_pointer_pos()
+                .is_some_and(|pos| pos.x < response.rect.center().x);
+            self.clicked = Some(MenuEvent::Adjust(index, if left { -1 } else { 1 }));
+        }
+    }
+    /// Goes back when `response` is clicked.
+    pub fn pointer_back(&mut self, response: &Response) {
+        if response.clicked() && self.can_use_pointer() {
+            self.clicked = Some(MenuEvent::Back);
+        }
+    }
+    fn pointer_hover(&mut self, index: usize, response: &Response) {
+        // A pointer resting over an item shouldn't take the focus back from a controller.
+        if response.hovered()
+            && response.ctx.input(|input| input.pointer.is_moving())
+            && self.can_use_pointer()
+        {
+            self.focus = index;
+        }
+    }
+}
+
+/// Makes `rect` respond to the pointer, on top of whatever is painted there.
+///
+/// It's above every other area so the backgrounds drawn with them can't take the pointer.
+/// An empty `rect`, like [`Rect::NOTHING`] for something that wasn't drawn, is never hit.
+pub fn hit_area(ctx: &Context, id: impl std::hash::Hash, rect: Rect) -> Response {
+    let (rect, sense) = if rect.is_positive() {
+        (rect, Sense::click_and_drag())
+    } else {
+        (Rect::from_min_max(Pos2::ZERO, Pos2::ZERO), Sense::hover())
+    };
+    Area::new(id)
+        .fixed_pos(rect.min)
+        .movable(false)
+        .order(Order::Tooltip)
+        .show(ctx, |ui| ui.allocate_exact_size(rect.size(), sense).1)
+        .inner
+}
@@ -8,6 +8,8 @@ pub struct PressInput {
     last: bool,
     /// Tracks how many frames the input has been "held".
     held: u32,
+    /// The frames left that a press is remembered until it's consumed.
+    buffer: u32,
 }
 impl PressInput {
     pub fn just_pressed(&self) -> bool {
@@ -28,6 +30,40 @@ impl PressInput {
     pub fn held(&self) -> u32 {
         self.held
     }
+    /// Whether there's a recent press that hasn't been consumed yet.
+    pub fn buffered(&self) -> bool {
+        self.buffer > 0
+    }
+    /// Uses up the buffered press, returning whether there was one.
+    pub fn consume(&mut self) -> bool {
+        std::mem::take(&mut self.buffer) > 0
+    }
+    /// Carries over the buffer from the `previous` step, remembering a new press for `frames` steps.
+    pub fn buffer_from(&mut self, previous: &PressInput, frames: u32) {
+        self.buffer = if self.just_pressed() {
+            frames.max(1)
+        } else {
+            previous.buffer.saturating_sub(1)
+        };
+    }
+    /// Holds on to a press from a rendered `frame`, even after it's released, until a step takes it.
+    pub fn latch(&mut self, frame: &PressInput) {
+        self.current |= frame.just_pressed();
+    }
+    /// Makes this the input of a fixed step following the `previous` step, so a press is
+    /// [`Self::just_pressed`] on exactly one step however many steps a rendered frame runs.
+    ///
+    /// `latched` is whether it was pressed since the `previous` step, which counts even if it
+    /// was released before this step.
+    pub fn step_from(&mut self, previous: &PressInput, latched: bool) {
+        self.current |= latched;
+        self.last = previous.current;
+        self.held = match (self.current, self.last) {
+            (true, true) => previous.held + 1,
+            (true, false) => 0,
+            (false, _) => previous.held,
+        };
+    }
     /// Applies a boolean value to the input for whether or not the button should be pressed.
     pub fn apply_bool(&mut self, pressed: bool) {
         self.current = pressed;
@@ -61,3 +97,75 @@ impl std::ops::BitOr for PressInput {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Runs a fixed step the way the session does, with the button `down` on the rendered frame.
+    fn step(previous: &PressInput, down: bool, latched: bool, frames: u32) -> PressInput {
+        let mut input = PressInput::default();
+        input.apply_bool(down);
+        input.step_from(previous, latched);
+        input.buffer_from(previous, frames);
+        input
+    }
+
+    #[test]
+    fn buffer_expires_on_the_last_frame() {
+        let mut input = step(&PressInput::default(), true, true, 3);
+        for _ in 1..3 {
+            input = step(&input, true, false, 3);
+            assert!(input.buffered());
+        }
+        input = step(&input, true, false, 3);
+        assert!(!input.buffered());
+    }
+
+    #[test]
+    fn zero_buffer_frames_still_keeps_the_press_for_its_step() {
+        let input = step(&PressInput::default(), true, true, 0);
+        assert!(input.buffered());
+        assert!(!step(&input, true, false, 0).buffered());
+    }
+
+    #[test]
+    fn consumed_press_isnt_carried_over() {
+        let mut input = step(&PressInput::default(), true, true, 3);
+        assert!(input.consume());
+        assert!(!input.consume());
+        assert!(!step(&input, true, false, 3).buffered());
+    }
+
+    #[test]
+    fn press_latched_on_the_frame_its_stepped() {
+        let mut frame = PressInput::default();
+        frame.apply_bool(true);
+        let mut latched = PressInput::default();
+        latched.latch(&frame);
+
+        let first = step(&PressInput::default(), true, latched.pressed(), 3);
+        assert!(first.just_pressed());
+        assert!(first.buffered());
+        // A second step on the same rendered frame doesn't press it again.
+        let second = step(&first, true, false, 3);
+        assert!(!second.just_pressed());
+        assert_eq!(second.held(), 1);
+    }
+
+    #[test]
+    fn press_released_before_a_step_still_counts() {
+        let mut frame = PressInput::default();
+        frame.apply_bool(true);
+        let mut latched = PressInput::default();
+        latched.latch(&frame);
+        frame.advance();
+        frame.apply_bool(false);
+        latched.latch(&frame);
+
+        let input = step(&PressInput::default(), false, latched.pressed(), 3);
+        assert!(input.just_pressed());
+        assert!(input.buffered());
+        assert!(step(&input, false, false, 3).just_released());
+    }
+}
@@ -0,0 +1,103 @@
+//! Smooths rendered motion between fixed simulation steps.
+//!
+//! A session runner that runs its systems in fixed steps should call
+//! [`restore_interpolated`] before running any steps, [`record_interpolated`]
+//! after each step, and [`apply_interpolated`] once it is done stepping for the
+//! rendered frame. Between those calls the [`Transform`] of every entity with an
+//! [`Interpolated`] component is blended between its last two simulated positions.
+
+use bones_framework::prelude::*;
+
+#[derive(HasSchema, Clone, Copy)]
+pub struct Interpolated {
+    /// The translation after the second to last step.
+    pub previous: Vec3,
+    /// The translation after the last step.
+    pub current: Vec3,
+    /// The blended translation that was last applied for rendering.
+    pub rendered: Vec3,
+    /// Moving further than this in a single step snaps to the new position
+    /// instead of blending, so teleports don't streak across the screen.
+    pub snap_distance: f32,
+    initialized: bool,
+}
+impl Default for Interpolated {
+    fn default() -> Self {
+        Self {
+            previous: default(),
+            current: default(),
+            rendered: default(),
+            snap_distance: 32.0,
+            initialized: false,
+        }
+    }
+}
+
+/// Puts the simulated translations back before running a step.
+///
+/// Translations that were changed since [`apply_interpolated`] are kept, and become both
+/// of the last simulated positions so they aren't blended away while no steps run.
+pub fn restore_interpolated(
+    entities: Res<Entities>,
+    mut interpolated: CompMut<Interpolated>,
+    mut transforms: CompMut<Transform>,
+) {
+    for (entity, interpolated) in entities.iter_with(&mut interpolated) {
+        let Some(transform) = transforms.get_mut(entity) else {
+            continue;
+        };
+        if !interpolated.initialized {
+            continue;
+        }
+        if transform.translation == interpolated.rendered {
+            transform.translation = interpolated.current;
+        } else {
+            interpolated.previous = transform.translation;
+            interpolated.current = transform.translation;
+            interpolated.rendered = transform.translation;
+        }
+    }
+}
+
+/// Records the simulated translations after a step.
+pub fn record_interpolated(
+    entities: Res<Entities>,
+    transforms: Comp<Transform>,
+    mut interpolated: CompMut<Interpolated>,
+) {
+    for (entity, interpolated) in entities.iter_with(&mut interpolated) {
+        let Some(transform) = transforms.get(entity) else {
+            continue;
+        };
+        let translation = transform.translation;
+
+        if !interpolated.initialized
+            || translation.distance(interpolated.current) > interpolated.snap_distance
+        {
+            interpolated.previous = translation;
+            interpolated.initialized = true;
+        } else {
+            interpolated.previous = interpolated.current;
+        }
+        interpolated.current = translation;
+    }
+}
+
+/// Blends the translations between the last two steps by `alpha`.
+pub fn apply_interpolated(
+    In(alpha): In<f32>,
+    entities: Res<Entities>,
+    mut interpolated: CompMut<Interpolated>,
+    mut transforms: CompMut<Transform>,
+) {
+    for (entity, interpolated) in entities.iter_with(&mut interpolated) {
+        let Some(transform) = transforms.get_mut(entity) else {
+            continue;
+        };
+        if !interpolated.initialized {
+            continue;
+        }
+        interpolated.rendered = interpolated.previous.lerp(interpolated.current, alpha);
+        transform.translation = interpolated.rendered;
+    }
+}
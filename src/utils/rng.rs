@@ -0,0 +1,42 @@
+use bones_framework::prelude::*;
+
+/// A small deterministic random number generator (xorshift64*).
+///
+/// The same seed always produces the same numbers, on every platform.
+#[derive(HasSchema, Clone, Copy)]
+pub struct SeededRng {
+    state: u64,
+}
+impl Default for SeededRng {
+    fn default() -> Self {
+        Self::new(0)
+    }
+}
+impl SeededRng {
+    pub fn new(seed: u64) -> Self {
+        // The state must never be zero or every number after it would be zero.
+        Self {
+            state: (seed ^ 0x9E37_79B9_7F4A_7C15).max(1),
+        }
+    }
+    pub fn next_u64(&mut self) -> u64 {
+        let mut x = self.state;
+        x ^= x >> 12;
+        x ^= x << 25;
+        x ^= x >> 27;
+        self.state = x;
+        x.wrapping_mul(0x2545_F491_4F6C_DD1D)
+    }
+    /// Returns a number from `0.0` up to, but not including, `1.0`.
+    pub fn f32(&mut self) -> f32 {
+        (self.next_u64() >> 40) as f32 / (1u64 << 24) as f32
+    }
+    /// Returns a number from `min` up to, but not including, `max`.
+    pub fn range(&mut self, min: f32, max: f32) -> f32 {
+        min + (max - min) * self.f32()
+    }
+    /// Returns an index into a collection of length `len`.
+    pub fn index(&mut self, len: usize) -> usize {
+        (self.next_u64() % len.max(1) as u64) as usize
+    }
+}